@@ -0,0 +1,84 @@
+use simple_grid::{Grid, GridPos, Wrap};
+
+#[derive(Clone, Copy, PartialEq)]
+enum MapCell {
+    Free,
+    Tree,
+}
+
+pub struct TobagganMap {
+    map: Grid<MapCell>,
+}
+
+fn get_map_from_input(file_name: &str) -> Result<TobagganMap, String> {
+    let text = input_helpers::read_file_to_string(file_name).map_err(|e| format!("{}: {}", file_name, e))?;
+    let map = Grid::parse(&text, |c, _pos| match c {
+        '.' => Ok(MapCell::Free),
+        '#' => Ok(MapCell::Tree),
+        _ => Err("'.' or '#'"),
+    })
+    .map_err(|e| e.with_file(file_name))?;
+
+    Ok(TobagganMap { map })
+}
+
+impl TobagganMap {
+    fn calculate_tree_hits_from_slope(
+        &self,
+        x_slope: usize,
+        y_slope: usize,
+    ) -> Vec<(usize, usize)> {
+        let num_rows = self.map.height;
+
+        // the map repeats to the right, so the column wraps back around onto it
+        let calculate_coord_from_slope_and_row = |row| (row, row * x_slope / y_slope);
+
+        // for every row, calculate each (row, col) where the tobaggan will travel
+        let row_steps = (0..num_rows).step_by(y_slope);
+        let possible_hits = row_steps.map(calculate_coord_from_slope_and_row);
+
+        // iterate over each cell traveled and return a hit if there was a tree there
+        let hits = possible_hits.filter(|(row, col)| {
+            let pos = GridPos {
+                row: *row as isize,
+                col: *col as isize,
+            };
+            self.map.get_wrapped(pos, Wrap::Horizontal) == Some(MapCell::Tree)
+        });
+        hits.collect()
+    }
+}
+
+pub struct Day03;
+
+impl solution::Solution for Day03 {
+    type Input = TobagganMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input_file: &str) -> Result<Self::Input, String> {
+        get_map_from_input(input_file)
+    }
+
+    fn part1(treemap: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+        let tree_hits = treemap.calculate_tree_hits_from_slope(3, 1);
+        Ok(tree_hits.len())
+    }
+
+    fn part2(treemap: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let slopes_to_test = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+        let product_of_tree_hits = {
+            let mut product = 1;
+            for (x_slope, y_slope) in slopes_to_test.iter() {
+                let tree_hits = treemap.calculate_tree_hits_from_slope(*x_slope, *y_slope);
+                product *= tree_hits.len();
+            }
+            product
+        };
+        Ok(product_of_tree_hits)
+    }
+}
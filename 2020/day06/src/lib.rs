@@ -0,0 +1,75 @@
+type MemberAnswers = String;
+type GroupAnswers = Vec<MemberAnswers>;
+
+fn get_group_answers_from_input(file_name: &str) -> Vec<GroupAnswers> {
+    let lines: Vec<String> = input_helpers::read_lines(file_name).collect();
+    input_helpers::sections(&lines)
+        .map(|section| section.lines.to_vec())
+        .collect()
+}
+
+fn count_questions_with_yes_answer(group_answers: &GroupAnswers) -> usize {
+    let mut yes_answers = [false; 26];
+    for member_answers in group_answers {
+        for answer in member_answers.chars() {
+            assert!(answer.is_ascii());
+            let answer_index = (answer as usize) - ('a' as usize);
+            yes_answers[answer_index] = true;
+        }
+    }
+
+    yes_answers.iter().filter(|a| **a).count()
+}
+
+fn count_questions_with_only_yes_answers(group_answers: &GroupAnswers) -> usize {
+    let mut yes_answers = [0usize; 26];
+    for member_answers in group_answers {
+        for answer in member_answers.chars() {
+            assert!(answer.is_ascii());
+            let answer_index = (answer as usize) - ('a' as usize);
+            yes_answers[answer_index] += 1;
+        }
+    }
+
+    let member_count = group_answers.len();
+    yes_answers
+        .iter()
+        .filter(|yes_answer_count| **yes_answer_count == member_count)
+        .count()
+}
+
+pub struct Day06;
+
+impl solution::Solution for Day06 {
+    type Input = Vec<GroupAnswers>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input_file: &str) -> Result<Self::Input, String> {
+        Ok(get_group_answers_from_input(input_file))
+    }
+
+    fn part1(group_answers_list: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let any_yes_answer_counts: Vec<usize> = group_answers_list
+            .iter()
+            .map(|g| count_questions_with_yes_answer(g))
+            .collect();
+        Ok(any_yes_answer_counts.iter().fold(0, |a, b| a + b))
+    }
+
+    fn part2(group_answers_list: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        for group_answers in group_answers_list {
+            println!("member count: {}", group_answers.len());
+        }
+
+        let all_yes_answer_counts: Vec<usize> = group_answers_list
+            .iter()
+            .map(|g| count_questions_with_only_yes_answers(g))
+            .collect();
+        Ok(all_yes_answer_counts.iter().fold(0, |a, b| a + b))
+    }
+}
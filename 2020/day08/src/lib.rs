@@ -0,0 +1,147 @@
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    Acc(isize),
+    Jmp(isize),
+    Nop(isize),
+}
+
+#[derive(Debug, PartialEq)]
+enum RunResult {
+    Corrupted,
+    Succeeded,
+}
+
+#[derive(Debug, Clone)]
+pub struct Program {
+    accumulator: isize,
+    instruction_tracker: Vec<bool>,
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    fn load(file_name: &str) -> Result<Self, String> {
+        let lines = input_helpers::try_read_all_lines(file_name)?;
+
+        let mut instructions = Vec::new();
+        for (line_idx, line) in lines.iter().enumerate() {
+            let line_number = line_idx + 1;
+            let (operation, argument) = input_helpers::scan!(line_number, line, "{} {}", String, isize)
+                .map_err(|e| e.with_file(file_name))?;
+
+            let instruction = match operation.as_str() {
+                "acc" => Instruction::Acc(argument),
+                "jmp" => Instruction::Jmp(argument),
+                "nop" => Instruction::Nop(argument),
+                _ => {
+                    let operation_str = line.split(' ').next().unwrap();
+                    let e = input_helpers::ParseError::at_substr(line_number, line, operation_str, "acc, jmp or nop");
+                    return Err(e.with_file(file_name).into());
+                }
+            };
+
+            instructions.push(instruction);
+        }
+
+        let accumulator = 0;
+        let instruction_tracker = instructions.iter().map(|_| false).collect();
+        Ok(Program {
+            accumulator,
+            instruction_tracker,
+            instructions,
+        })
+    }
+
+    fn run(&mut self) -> RunResult {
+        let mut instr_index: usize = 0;
+        while instr_index < self.instruction_tracker.len() && !self.instruction_tracker[instr_index]
+        {
+            let instruction = &self.instructions[instr_index];
+            self.instruction_tracker[instr_index] = true;
+
+            let instr_inc = match instruction {
+                Instruction::Acc(arg) => {
+                    self.accumulator += arg;
+                    1
+                }
+                Instruction::Jmp(arg) => *arg,
+                Instruction::Nop(_) => 1,
+            };
+            instr_index = ((instr_index as isize) + instr_inc) as usize;
+        }
+
+        if instr_index < self.instructions.len() {
+            RunResult::Corrupted // we looped
+        } else {
+            assert_eq!(instr_index, self.instructions.len());
+            RunResult::Succeeded // we completed the program
+        }
+    }
+
+    fn find_corrupted_instruction(&self) -> Option<(Instruction, usize)> {
+        for i in 0..self.instructions.len() {
+            let mut scratch_program = self.clone();
+            let new_instruction = match scratch_program.instructions[i] {
+                Instruction::Acc(_) => continue,
+                Instruction::Jmp(arg) => Instruction::Nop(arg),
+                Instruction::Nop(arg) => Instruction::Jmp(arg),
+            };
+
+            scratch_program.instructions[i] = new_instruction;
+            match scratch_program.run() {
+                RunResult::Corrupted => continue,
+                RunResult::Succeeded => return Some((new_instruction, i)),
+            }
+        }
+
+        None
+    }
+
+    fn fix_instruction(&mut self, fixed_instruction: Instruction, instruction_index: usize) {
+        self.instructions[instruction_index] = fixed_instruction;
+    }
+
+    fn acc(&self) -> isize {
+        self.accumulator
+    }
+}
+
+pub struct Day08;
+
+impl solution::Solution for Day08 {
+    type Input = Program;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input_file: &str) -> Result<Self::Input, String> {
+        Program::load(input_file)
+    }
+
+    fn part1(program: &Self::Input, args: &[String]) -> Result<isize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let mut program = program.clone();
+
+        let run_result = program.run();
+        assert_eq!(run_result, RunResult::Corrupted);
+        Ok(program.acc())
+    }
+
+    fn part2(program: &Self::Input, args: &[String]) -> Result<isize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let mut program = program.clone();
+
+        let (fixed_instruction, instruction_index) = program
+            .find_corrupted_instruction()
+            .ok_or(String::from("No corrupted instruction found"))?;
+        println!(
+            "Instruction fix: {:?},{}",
+            fixed_instruction, instruction_index
+        );
+
+        program.fix_instruction(fixed_instruction, instruction_index);
+        let run_result = program.run();
+        assert_eq!(run_result, RunResult::Succeeded);
+        Ok(program.acc())
+    }
+}
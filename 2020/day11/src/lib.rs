@@ -0,0 +1,135 @@
+extern crate input_helpers;
+use simple_grid::automaton::Automaton;
+use simple_grid::{Dir8, Grid, GridPos};
+use std::iter::FromIterator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SeatCell {
+    Floor,
+    Free,
+    Occupied,
+}
+
+impl SeatCell {
+    fn from_char(c: char) -> Result<SeatCell, &'static str> {
+        match c {
+            '.' => Ok(SeatCell::Floor),
+            'L' => Ok(SeatCell::Free),
+            '#' => Ok(SeatCell::Occupied),
+            _ => Err("'.', 'L' or '#'"),
+        }
+    }
+
+    fn to_ascii_char(&self) -> char {
+        match self {
+            SeatCell::Floor => '.',
+            SeatCell::Free => 'L',
+            SeatCell::Occupied => '#',
+        }
+    }
+
+    fn get_update(&self, occupied_seat_count: usize) -> Self {
+        match self {
+            SeatCell::Floor => SeatCell::Floor,
+            SeatCell::Free => {
+                if occupied_seat_count == 0 {
+                    SeatCell::Occupied
+                } else {
+                    SeatCell::Free
+                }
+            }
+            SeatCell::Occupied => {
+                if occupied_seat_count >= 5 {
+                    SeatCell::Free
+                } else {
+                    SeatCell::Occupied
+                }
+            }
+        }
+    }
+}
+
+pub struct SeatGrid {
+    seats: Grid<SeatCell>,
+}
+
+impl SeatGrid {
+    fn from_file(file_name: &str) -> Result<Self, String> {
+        let text = input_helpers::read_file_to_string(file_name).map_err(|e| format!("{}: {}", file_name, e))?;
+        let seats = Grid::parse(&text, |c, _pos| SeatCell::from_char(c)).map_err(|e| e.with_file(file_name))?;
+        if seats.cells.is_empty() {
+            return Err(format!("{}: No seats in the grid", file_name));
+        }
+
+        Ok(SeatGrid { seats })
+    }
+}
+
+fn format_grid_as_str(seats: &Grid<SeatCell>) -> String {
+    let mut char_vec = Vec::new();
+    for row in seats.cells.chunks(seats.width) {
+        for cell in row {
+            char_vec.push(cell.to_ascii_char());
+        }
+        char_vec.push('\n');
+    }
+    String::from_iter(char_vec.iter())
+}
+
+/// The first seat in sight in each of the 8 directions, looking over the floor.
+fn visible_seats(seats: &Grid<SeatCell>, pos: GridPos, visible: &mut Vec<GridPos>) {
+    visible.extend(
+        Dir8::ALL
+            .iter()
+            .filter_map(|dir| seats.first_visible(pos, *dir, |cell| cell != SeatCell::Floor)),
+    );
+}
+
+fn update_seat(seat: SeatCell, visible_seats: &[SeatCell]) -> SeatCell {
+    let occupied_seat_count = visible_seats
+        .iter()
+        .filter(|seat| **seat == SeatCell::Occupied)
+        .count();
+    seat.get_update(occupied_seat_count)
+}
+
+fn get_occupied_seat_count(seats: &Grid<SeatCell>) -> usize {
+    seats
+        .cells
+        .iter()
+        .filter(|seat| **seat == SeatCell::Occupied)
+        .count()
+}
+
+pub struct Day11;
+
+impl solution::Solution for Day11 {
+    type Input = SeatGrid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input_file: &str) -> Result<Self::Input, String> {
+        SeatGrid::from_file(input_file)
+    }
+
+    fn part1(_seat_grid: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+        Err("Pt 1 solution was not kept; only pt 2 is implemented".into())
+    }
+
+    fn part2(seat_grid: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let mut seats = Automaton::new(seat_grid.seats.clone());
+        loop {
+            println!("{}", format_grid_as_str(seats.world()));
+            let updated = seats.step(visible_seats, update_seat);
+
+            if !updated {
+                break;
+            }
+        }
+
+        Ok(get_occupied_seat_count(seats.world()))
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+fn play_memory_game(starting_numbers: &[usize], target_turn: usize) -> usize {
+    let mut age_map = HashMap::new();
+    for i in 0..starting_numbers.len() - 1 {
+        age_map.insert(starting_numbers[i], i + 1);
+    }
+
+    let mut last_number_spoken = *starting_numbers.last().unwrap();
+    for current_turn in starting_numbers.len() + 1..target_turn + 1 {
+        let last_turn = current_turn - 1;
+        let num_spoken = match age_map.get(&last_number_spoken) {
+            Some(age) => last_turn - age,
+            None => 0,
+        };
+        age_map.insert(last_number_spoken, last_turn);
+        last_number_spoken = num_spoken;
+    }
+
+    last_number_spoken
+}
+
+fn get_starting_numbers_from_input(file_name: &str) -> Vec<usize> {
+    let line = input_helpers::read_lines(file_name).next().unwrap();
+    line.split(',').map(|n| n.parse().unwrap()).collect()
+}
+
+pub struct Day15;
+
+impl solution::Solution for Day15 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input_file: &str) -> Result<Self::Input, String> {
+        let input = get_starting_numbers_from_input(input_file);
+        println!("Input: {:?}", input);
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+        Ok(play_memory_game(input, 2020))
+    }
+
+    fn part2(input: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+        // TODO: improve time to complete. this is SUPER slow, but it finishes
+        Ok(play_memory_game(input, 30000000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_game_pt1_samples() {
+        assert_eq!(play_memory_game(&[0, 3, 6], 2020), 436);
+        assert_eq!(play_memory_game(&[1, 3, 2], 2020), 1);
+        assert_eq!(play_memory_game(&[2, 1, 3], 2020), 10);
+        assert_eq!(play_memory_game(&[1, 2, 3], 2020), 27);
+        assert_eq!(play_memory_game(&[2, 3, 1], 2020), 78);
+        assert_eq!(play_memory_game(&[3, 2, 1], 2020), 438);
+        assert_eq!(play_memory_game(&[3, 1, 2], 2020), 1836);
+    }
+
+    #[test]
+    fn memory_game_pt1() {
+        assert_eq!(play_memory_game(&[0, 3, 1, 6, 7, 5], 2020), 852);
+    }
+
+    #[test]
+    fn memory_game_pt2_samples() {
+        assert_eq!(play_memory_game(&[0, 3, 6], 30000000), 175594);
+        assert_eq!(play_memory_game(&[1, 3, 2], 30000000), 2578);
+        assert_eq!(play_memory_game(&[2, 1, 3], 30000000), 3544142);
+        assert_eq!(play_memory_game(&[1, 2, 3], 30000000), 261214);
+        assert_eq!(play_memory_game(&[2, 3, 1], 30000000), 6895259);
+        assert_eq!(play_memory_game(&[3, 2, 1], 30000000), 18);
+        assert_eq!(play_memory_game(&[3, 1, 2], 30000000), 362);
+    }
+
+    #[test]
+    fn memory_game_pt2() {
+        assert_eq!(play_memory_game(&[0, 3, 1, 6, 7, 5], 30000000), 6007666);
+    }
+}
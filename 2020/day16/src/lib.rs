@@ -0,0 +1,271 @@
+// TODO: I am REALLY unsatisfied with how difficult it is to read this implementation (and likely how inefficient it is.)
+// I suspect solving the problems goes hand in hand and involves
+// 1. a better data representation
+// 2. while leads to clearer processing of data
+// 3. which leads to better names for variables
+
+use input_helpers::{parse_substr, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IncRange {
+    min: usize,
+    max: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TicketRule {
+    field_name: String,
+    valid_ranges: Vec<IncRange>,
+}
+
+type Ticket = Vec<usize>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TestInput {
+    rules: Vec<TicketRule>,
+    my_ticket: Ticket,
+    nearby_tickets: Vec<Ticket>,
+}
+
+fn get_completely_invalid_ticket_value(ticket_values: &[usize], ticket_rules: &[TicketRule]) -> Option<usize> {
+    for ticket_value in ticket_values {
+        if ticket_rules.iter().all(|rule| rule.is_value_completely_invalid(*ticket_value)) {
+            return Some(*ticket_value);
+        }
+    }
+
+    None
+}
+
+fn get_ticket_scanning_error_rate(tickets: &[Ticket], ticket_rules: &[TicketRule]) -> usize {
+    tickets.iter().map(|ticket| get_completely_invalid_ticket_value(&ticket, ticket_rules).unwrap_or(0)).sum()
+}
+
+fn discard_invalid_tickets(tickets: &[Ticket], ticket_rules: &[TicketRule]) -> Vec<Ticket> {
+    tickets.iter().filter(|&ticket| get_completely_invalid_ticket_value(ticket, ticket_rules).is_none()).map(|e| e.clone()).collect()
+}
+
+fn classify_ticket_values(tickets: &[Ticket], ticket_rules: &[TicketRule]) -> Vec<String> {
+    let rule_count = ticket_rules.len();
+
+    let mut ordered_rule_names = vec![String::new();rule_count];
+    ordered_rule_names.reserve(ticket_rules.len());
+
+    let mut valid_rules_for_cols = Vec::new();
+
+    for ticket_value_col in 0..rule_count { // there is one ticket_value_col for each rule
+        let mut possible_matching_rules = ticket_rules.to_vec();
+        for ticket_val in tickets.iter().map(|ticket| ticket[ticket_value_col]) {
+            for i in (0..possible_matching_rules.len()).rev() {
+                if possible_matching_rules[i].is_value_completely_invalid(ticket_val) {
+                    possible_matching_rules.swap_remove(i);
+                }
+            }
+        }
+
+        valid_rules_for_cols.push((ticket_value_col, possible_matching_rules));
+    }
+
+    while !valid_rules_for_cols.is_empty() {
+        let complete_rule_index = valid_rules_for_cols.iter().position(|(_, rules)| rules.len() == 1).unwrap();
+        let (rule_index, mut complete_rule) = valid_rules_for_cols.swap_remove(complete_rule_index);
+        let complete_rule = complete_rule.swap_remove(0);
+
+        for (_, rules) in &mut valid_rules_for_cols {
+            if let Some(index) = rules.iter().position(|ticket_rule| ticket_rule.field_name == complete_rule.field_name) {
+                rules.swap_remove(index);
+            }
+        }
+
+        ordered_rule_names[rule_index] = complete_rule.field_name;
+    }
+
+    ordered_rule_names
+}
+
+impl IncRange {
+    fn new(min: usize, max: usize) -> Self {
+        IncRange {min, max}
+    }
+
+    fn is_in_range(&self, v: usize) -> bool {
+        self.min <= v && v <= self.max
+    }
+}
+
+impl TicketRule {
+    fn new(field_name: &str, valid_ranges: &[IncRange]) -> Self {
+        TicketRule {
+            field_name: String::from(field_name),
+            valid_ranges: valid_ranges.to_vec(),
+        }
+    }
+
+    fn is_value_completely_invalid(&self, v: usize) -> bool {
+        self.valid_ranges.iter().all(|r| !r.is_in_range(v))
+    }
+}
+
+impl TestInput {
+    fn parse_rule(line_number: usize, line: &str) -> Result<TicketRule, ParseError> {
+        let mut valid_ranges = Vec::new();
+
+        let (field_name, range_strs) = line.split_once(": ").ok_or_else(|| {
+            ParseError::whole_line(line_number, line, "a rule like 'class: 1-3 or 5-7'")
+        })?;
+
+        for range_str in range_strs.split(" or ") {
+            let (min_str, max_str) = range_str.split_once('-').ok_or_else(|| {
+                ParseError::at_substr(line_number, line, range_str, "a range like '1-3'")
+            })?;
+            let min = parse_substr(line_number, line, min_str, "the start of a range")?;
+            let max = parse_substr(line_number, line, max_str, "the end of a range")?;
+            valid_ranges.push(IncRange::new(min, max));
+        }
+
+        Ok(TicketRule::new(field_name, &valid_ranges))
+    }
+
+    fn parse_ticket(line_number: usize, line: &str) -> Result<Ticket, ParseError> {
+        line.split(',')
+            .map(|ticket_value| parse_substr(line_number, line, ticket_value, "a ticket value"))
+            .collect()
+    }
+
+    fn from_file(file_name: &str) -> Result<Self, String> {
+        let lines: Vec<String> = input_helpers::try_read_all_lines(file_name)?;
+        TestInput::parse(&lines).map_err(|e| e.with_file(file_name).into())
+    }
+
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        let [rule_section, my_ticket_section, nearby_ticket_section] = input_helpers::exact_sections(
+            lines,
+            ["the ticket rules", "your ticket", "the nearby tickets"],
+        )?;
+
+        let my_ticket_section = my_ticket_section.strip_header("your ticket:")?;
+        let nearby_ticket_section = nearby_ticket_section.strip_header("nearby tickets:")?;
+        if my_ticket_section.lines.len() != 1 {
+            // the header if the ticket is missing, otherwise the first line too many
+            let line_number = my_ticket_section.first_line_number - 1 + my_ticket_section.lines.len().min(2);
+            return Err(ParseError::whole_line(
+                line_number,
+                &lines[line_number - 1],
+                "exactly 1 line for your ticket",
+            ));
+        }
+
+        Ok(TestInput {
+            rules: rule_section
+                .numbered_lines()
+                .map(|(line_number, line)| TestInput::parse_rule(line_number, line))
+                .collect::<Result<_, _>>()?,
+            my_ticket: TestInput::parse_ticket(my_ticket_section.first_line_number, &my_ticket_section.lines[0])?,
+            nearby_tickets: nearby_ticket_section
+                .numbered_lines()
+                .map(|(line_number, line)| TestInput::parse_ticket(line_number, line))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+pub struct Day16;
+
+impl solution::Solution for Day16 {
+    type Input = TestInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input_file: &str) -> Result<Self::Input, String> {
+        TestInput::from_file(input_file)
+    }
+
+    fn part1(test_input: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+        Ok(get_ticket_scanning_error_rate(&test_input.nearby_tickets, &test_input.rules))
+    }
+
+    fn part2(test_input: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let filtered_tickets = discard_invalid_tickets(&test_input.nearby_tickets, &test_input.rules);
+        let ordered_rules = classify_ticket_values(&filtered_tickets, &test_input.rules);
+        println!("Each column's rule: {:?}", ordered_rules);
+
+        let departure_rules = ordered_rules.iter().enumerate().filter(|(_, rule_name)| rule_name.starts_with("departure"));
+        let mut departure_values = std::collections::HashMap::new();
+        for (rule_index, rule_name) in departure_rules {
+            departure_values.insert(rule_name, test_input.my_ticket[rule_index]);
+        }
+        println!("my ticket's departure rows: {:?}", departure_values);
+
+        Ok(departure_values.iter().map(|(_, v)| v).product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_simple_test_input() -> TestInput {
+        // class: 1-3 or 5-7
+        // row: 6-11 or 33-44
+        // seat: 13-40 or 45-50
+        //
+        // your ticket:
+        // 7,1,14
+        //
+        // nearby tickets:
+        // 7,3,47 (valid)
+        // 40,**4**,50 (invalid)
+        // **55**,2,20 (invalid)
+        // 38,6,**12** (invalid)
+        let rules = vec![
+            TicketRule::new("class", &[IncRange::new(1, 3), IncRange::new(5, 7)]),
+            TicketRule::new("row", &[IncRange::new(6, 11), IncRange::new(33, 44)]),
+            TicketRule::new("seat", &[IncRange::new(13, 40), IncRange::new(45, 50)]),
+        ];
+
+        let my_ticket = vec![7, 1, 14];
+
+        let nearby_tickets = vec![
+            vec![7, 3, 47], // valid
+            vec![40, 4, 50], // invalid (4)
+            vec![55, 2, 20], // invalid (55)
+            vec![38, 6, 12], // invalid (12)
+            ];
+
+        TestInput {
+            rules,
+            my_ticket,
+            nearby_tickets,
+        }
+    }
+
+    #[test]
+    fn completely_invalid_ticket_test() {
+        let simple_test_input = get_simple_test_input();
+        let ticket_rules = simple_test_input.rules;
+        let tickets = simple_test_input.nearby_tickets;
+
+        assert_eq!(get_completely_invalid_ticket_value(&tickets[0], &ticket_rules), None);
+        assert_eq!(get_completely_invalid_ticket_value(&tickets[1], &ticket_rules), Some(4));
+        assert_eq!(get_completely_invalid_ticket_value(&tickets[2], &ticket_rules), Some(55));
+        assert_eq!(get_completely_invalid_ticket_value(&tickets[3], &ticket_rules), Some(12));
+        assert_eq!(get_ticket_scanning_error_rate(&tickets, &ticket_rules), 71);
+    }
+
+    #[test]
+    fn test_input_parsing( ) {
+        let test_input = TestInput::from_file("src/simple_input.txt").unwrap();
+        let expected_test_input = get_simple_test_input();
+        assert_eq!(test_input, expected_test_input);
+    }
+
+    #[test]
+    fn test_col_classification() {
+        let simple_test_input = TestInput::from_file("src/simple2_input.txt").unwrap();
+        let ordered_rules = classify_ticket_values(&simple_test_input.nearby_tickets, &simple_test_input.rules);
+        assert_eq!(ordered_rules, ["row", "class", "seat"]);
+    }
+}
\ No newline at end of file
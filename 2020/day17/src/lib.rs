@@ -0,0 +1,220 @@
+use simple_grid::automaton::Automaton;
+use simple_grid::{PointN, SparseGrid};
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CubeState {
+    Inactive,
+    Active,
+}
+
+/// A Conway Pocket Dimension with `D` axes. Only the active cubes are stored, so it can grow as
+/// far as the simulation takes it.
+struct PocketDimension<const D: usize> {
+    cubes: Automaton<SparseGrid<CubeState, PointN<D>>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SeedGrid {
+    width: usize,  // addressable size of x-axis
+    height: usize, // addressable size of y-axis
+    grid: Vec<CubeState>,
+}
+
+impl<const D: usize> PocketDimension<D> {
+    fn new(seed_grid: &SeedGrid) -> Self {
+        // the seed is a slice of the x-y plane, at 0 along every other axis
+        let mut cubes = SparseGrid::new(CubeState::Inactive);
+        for row in 0..seed_grid.height {
+            for col in 0..seed_grid.width {
+                let mut pos = PointN::ORIGIN;
+                pos.coords[0] = col as isize;
+                pos.coords[1] = row as isize;
+                cubes.set(pos, seed_grid.get(row, col));
+            }
+        }
+
+        Self {
+            cubes: Automaton::new(cubes),
+        }
+    }
+
+    fn get_active_cell_count(&self) -> usize {
+        self.cubes
+            .world()
+            .iter()
+            .filter(|(_, state)| *state == CubeState::Active)
+            .count()
+    }
+
+    fn simulate(&mut self) {
+        self.cubes.step(
+            |cubes, pos, neighbors| neighbors.extend(cubes.neighbors(pos)),
+            |state, neighbors| {
+                let active_neighbor_count = neighbors
+                    .iter()
+                    .filter(|neighbor| **neighbor == CubeState::Active)
+                    .count();
+                match (state, active_neighbor_count) {
+                    (CubeState::Active, 2) => CubeState::Active,
+                    (CubeState::Active, 3) => CubeState::Active,
+                    (CubeState::Active, _) => CubeState::Inactive,
+                    (CubeState::Inactive, 3) => CubeState::Active,
+                    (CubeState::Inactive, _) => CubeState::Inactive,
+                }
+            },
+        );
+    }
+}
+
+impl SeedGrid {
+    fn new(grid: &[Vec<CubeState>]) -> Self {
+        let mut flat_grid = Vec::new();
+        flat_grid.extend(grid.iter().flatten());
+        Self {
+            width: grid[0].len(),
+            height: grid.len(),
+            grid: flat_grid,
+        }
+    }
+
+    fn from_file(file_name: &str) -> Self {
+        let mut grid_rows = Vec::new();
+        for line in input_helpers::read_lines(file_name) {
+            let grid_cols = line
+                .chars()
+                .map(|c| match c {
+                    '.' => CubeState::Inactive,
+                    '#' => CubeState::Active,
+                    _ => panic!("Invalid char! {}", c),
+                })
+                .collect();
+            grid_rows.push(grid_cols);
+        }
+
+        SeedGrid::new(&grid_rows)
+    }
+
+    fn get(&self, row: usize, col: usize) -> CubeState {
+        self.grid[row * self.width + col]
+    }
+}
+
+const SIMULATION_COUNT: usize = 6;
+
+fn count_active_cubes_after_boot(seed: &SeedGrid, dims: usize) -> Result<usize, String> {
+    fn boot<const D: usize>(seed: &SeedGrid) -> usize {
+        let mut cpd = PocketDimension::<D>::new(seed);
+        for _ in 0..SIMULATION_COUNT {
+            cpd.simulate();
+        }
+
+        cpd.get_active_cell_count()
+    }
+
+    match dims {
+        2 => Ok(boot::<2>(seed)),
+        3 => Ok(boot::<3>(seed)),
+        4 => Ok(boot::<4>(seed)),
+        5 => Ok(boot::<5>(seed)),
+        6 => Ok(boot::<6>(seed)),
+        _ => Err(format!(
+            "Can't simulate {} dimensions; dims has to be from 2 to 6",
+            dims
+        )),
+    }
+}
+
+fn options() -> input_helpers::Options {
+    input_helpers::Options::new()
+        .value(
+            "dims1",
+            3,
+            "how many dimensions part 1's pocket dimension has",
+        )
+        .value(
+            "dims2",
+            4,
+            "how many dimensions part 2's pocket dimension has",
+        )
+}
+
+pub struct Day17;
+
+impl solution::Solution for Day17 {
+    type Input = SeedGrid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input_file: &str) -> Result<Self::Input, String> {
+        Ok(SeedGrid::from_file(input_file))
+    }
+
+    fn part1(seed: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let dims = options().parse(args)?.get("dims1")?;
+        Ok(count_active_cubes_after_boot(seed, dims)?)
+    }
+
+    fn part2(seed: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let dims = options().parse(args)?.get("dims2")?;
+        Ok(count_active_cubes_after_boot(seed, dims)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solution::Solution;
+
+    #[test]
+    fn basic_3d_setup_test() {
+        let seed = SeedGrid::new(&[vec![CubeState::Active; 1]]);
+        let cpd = PocketDimension::<3>::new(&seed);
+
+        assert_eq!(cpd.cubes.world().get(PointN::ORIGIN), CubeState::Active);
+        for neighbor in PointN::<3>::neighbor_offsets() {
+            assert_eq!(cpd.cubes.world().get(neighbor), CubeState::Inactive);
+        }
+
+        assert_eq!(cpd.get_active_cell_count(), 1);
+    }
+
+    #[test]
+    fn basic_3d_simulation_test() {
+        let seed = SeedGrid::new(&[
+            vec![CubeState::Inactive, CubeState::Active, CubeState::Inactive],
+            vec![CubeState::Inactive, CubeState::Inactive, CubeState::Active],
+            vec![CubeState::Active, CubeState::Active, CubeState::Active],
+        ]);
+
+        let simulation_count = 6;
+        let mut cpd = PocketDimension::<3>::new(&seed);
+        assert_eq!(cpd.get_active_cell_count(), 5);
+
+        for _ in 0..simulation_count {
+            cpd.simulate();
+        }
+
+        assert_eq!(cpd.get_active_cell_count(), 112);
+    }
+
+    #[test]
+    fn dims_option_picks_the_dimension() {
+        let seed = SeedGrid::from_file("src/simple_input.txt");
+        let dims = vec![String::from("dims1=4"), String::from("dims2=3")];
+        assert_eq!(Day17::part1(&seed, &dims), Ok(848));
+        assert_eq!(Day17::part2(&seed, &dims), Ok(112));
+        assert!(Day17::part2(&seed, &[String::from("dims2=7")]).is_err());
+    }
+
+    #[test]
+    fn build_seed_from_file_test() {
+        let seed = SeedGrid::from_file("src/simple_input.txt");
+        let expected_seed = SeedGrid::new(&[
+            vec![CubeState::Inactive, CubeState::Active, CubeState::Inactive],
+            vec![CubeState::Inactive, CubeState::Inactive, CubeState::Active],
+            vec![CubeState::Active, CubeState::Active, CubeState::Active],
+        ]);
+        assert_eq!(seed, expected_seed);
+    }
+}
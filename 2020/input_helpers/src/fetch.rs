@@ -0,0 +1,202 @@
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+
+/// Downloads puzzle inputs. Each user gets their own inputs, so every request needs the
+/// `session` cookie from a logged in browser.
+pub struct Fetcher {
+    pub(crate) base_url: String,
+    pub(crate) session: String,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str) -> Fetcher {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// A fetcher for the real site (or `AOC_BASE_URL` if it's set), using the session from
+    /// [`read_session`].
+    pub fn from_env() -> Result<Fetcher, String> {
+        let base_url =
+            std::env::var(BASE_URL_ENV_VAR).unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+        Ok(Fetcher::new(&base_url, &read_session()?))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn input_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    /// Downloads the input for `year`/`day`, always hitting the server.
+    pub fn fetch_input(&self, year: u32, day: u32) -> Result<String, String> {
+        let url = self.input_url(year, day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("Failed to read input from {}: {}", url, e)),
+            Err(ureq::Error::Status(400, _)) => Err(format!(
+                "{} rejected the session cookie; is it out of date?",
+                url
+            )),
+            Err(ureq::Error::Status(404, _)) => {
+                Err(format!("{} not found; is the puzzle unlocked yet?", url))
+            }
+            Err(e) => Err(format!("Failed to fetch input: {}", e)),
+        }
+    }
+
+    /// Returns `input_file`, downloading the input for `year`/`day` into it first if it doesn't
+    /// exist yet. An input that's already on disk is never downloaded again.
+    pub fn fetch_input_cached<P>(
+        &self,
+        year: u32,
+        day: u32,
+        input_file: P,
+    ) -> Result<PathBuf, String>
+    where
+        P: AsRef<Path>,
+    {
+        let input_file = input_file.as_ref();
+        if input_file.is_file() {
+            return Ok(input_file.to_path_buf());
+        }
+
+        let input = self.fetch_input(year, day)?;
+        if let Some(parent) = input_file.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        std::fs::write(input_file, input)
+            .map_err(|e| format!("Failed to write {}: {}", input_file.display(), e))?;
+
+        Ok(input_file.to_path_buf())
+    }
+}
+
+pub(crate) const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to the config file from
+/// [`session_config_file`].
+pub fn read_session() -> Result<String, String> {
+    if let Ok(session) = std::env::var(SESSION_ENV_VAR) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let config_file = session_config_file().ok_or(format!(
+        "No session cookie! Set {} or add a session config file",
+        SESSION_ENV_VAR
+    ))?;
+    match std::fs::read_to_string(&config_file) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(format!(
+            "No session cookie! Set {} or write it to {}",
+            SESSION_ENV_VAR,
+            config_file.display()
+        )),
+    }
+}
+
+/// `$XDG_CONFIG_HOME/aoc/session`, or `~/.config/aoc/session`.
+pub fn session_config_file() -> Option<PathBuf> {
+    let config_dir = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(config_dir.join("aoc").join("session"))
+}
+
+/// Where a day keeps its real input: `dayNN/src/input.txt` for crates that live directly in the
+/// day dir (2020), otherwise `dayNN/input.txt` (2024).
+pub fn input_file_for_day<P>(day_dir: P) -> PathBuf
+where
+    P: AsRef<Path>,
+{
+    let day_dir = day_dir.as_ref();
+    if day_dir.join("Cargo.toml").is_file() {
+        day_dir.join("src").join("input.txt")
+    } else {
+        day_dir.join("input.txt")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fetcher;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    /// Serves a single request with `body`, handing back the request's header lines.
+    fn serve_once(body: &'static str) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request_lines = vec![];
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                request_lines.push(line.trim().to_string());
+            }
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            request_lines
+        });
+
+        (base_url, server)
+    }
+
+    #[test]
+    fn fetches_with_session_and_caches() {
+        let (base_url, server) = serve_once("1 2 3\n");
+        let fetcher = Fetcher::new(&base_url, "abc123\n");
+
+        let cache_dir =
+            std::env::temp_dir().join(format!("input_helpers_fetch_{}", std::process::id()));
+        let input_file = cache_dir.join("day07").join("input.txt");
+        let _ = std::fs::remove_dir_all(&cache_dir);
+
+        let fetched = fetcher.fetch_input_cached(2024, 7, &input_file).unwrap();
+        assert_eq!(std::fs::read_to_string(&fetched).unwrap(), "1 2 3\n");
+
+        let request_lines = server.join().unwrap();
+        assert_eq!(request_lines[0], "GET /2024/day/7/input HTTP/1.1");
+        assert!(request_lines.iter().any(|l| l == "Cookie: session=abc123"));
+
+        // The server is gone, so this only works if the cached file is used.
+        let cached = fetcher.fetch_input_cached(2024, 7, &input_file).unwrap();
+        assert_eq!(cached, input_file);
+
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+    }
+}
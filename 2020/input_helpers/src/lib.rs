@@ -1,11 +1,28 @@
 use std::fs::File;
+use std::io;
 use std::io::BufRead;
 use std::io::BufReader;
 use std::io::Read;
 use std::path::Path;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+mod options;
+mod parse_error;
+mod scan;
+mod sections;
+pub use options::{Options, OptionsError, ParsedOptions};
+pub use parse_error::{parse_substr, ParseError};
+pub use scan::{ints, scan_fields, signed_ints};
+pub use sections::{exact_sections, sections, two_sections, Section, Sections};
+
+#[cfg(feature = "fetch")]
+pub mod fetch;
+#[cfg(feature = "fetch")]
+pub mod submit;
 
 pub struct Lines {
-    lines: std::io::Lines<BufReader<File>>,
+    lines: TryLines,
 }
 
 impl Iterator for Lines {
@@ -14,7 +31,7 @@ impl Iterator for Lines {
     fn next(&mut self) -> Option<String> {
         self.lines
             .next()
-            .map(|result_string| result_string.unwrap())
+            .map(|result_string| result_string.unwrap_or_else(|e| panic!("{}", e)))
     }
 }
 
@@ -22,16 +39,92 @@ pub fn read_lines<P>(file_name: P) -> Lines
 where
     P: AsRef<Path>,
 {
-    let file = File::open(file_name).unwrap();
     Lines {
-        lines: BufReader::new(file).lines(),
+        lines: try_read_lines(file_name).unwrap_or_else(|e| panic!("{}", e)),
     }
 }
 
+/// Like [`Lines`], but a line that can't be read is returned as an error (naming the file and
+/// line number) instead of panicking.
+pub struct TryLines {
+    file_name: PathBuf,
+    line_number: usize,
+    lines: std::io::Lines<Box<dyn BufRead>>,
+}
+
+impl Iterator for TryLines {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<io::Result<String>> {
+        let line = self.lines.next()?;
+        self.line_number += 1;
+        Some(line.map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("{}:{}: {}", self.file_name.display(), self.line_number, e),
+            )
+        }))
+    }
+}
+
+/// The file name that means "read the input from stdin", so an example can be piped in.
+pub const STDIN_FILE_NAME: &str = "-";
+
+fn is_stdin(file_name: &Path) -> bool {
+    file_name == Path::new(STDIN_FILE_NAME)
+}
+
+/// All of stdin. It's read once and kept, since a day's input gets parsed again for each part.
+fn read_stdin() -> io::Result<&'static str> {
+    static STDIN: OnceLock<String> = OnceLock::new();
+    if let Some(contents) = STDIN.get() {
+        return Ok(contents);
+    }
+
+    let mut contents = String::new();
+    io::stdin()
+        .read_to_string(&mut contents)
+        .map_err(|e| io::Error::new(e.kind(), format!("stdin: {}", e)))?;
+    Ok(STDIN.get_or_init(|| contents))
+}
+
+pub fn try_read_lines<P>(file_name: P) -> io::Result<TryLines>
+where
+    P: AsRef<Path>,
+{
+    let file_name = file_name.as_ref();
+    let reader: Box<dyn BufRead> = if is_stdin(file_name) {
+        Box::new(read_stdin()?.as_bytes())
+    } else {
+        let file = File::open(file_name)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", file_name.display(), e)))?;
+        Box::new(BufReader::new(file))
+    };
+    Ok(TryLines {
+        file_name: file_name.to_path_buf(),
+        line_number: 0,
+        lines: reader.lines(),
+    })
+}
+
+/// Reads every line of `file_name`, for parsers that want the whole input up front.
+pub fn try_read_all_lines<P>(file_name: P) -> Result<Vec<String>, String>
+where
+    P: AsRef<Path>,
+{
+    try_read_lines(file_name)
+        .and_then(|lines| lines.collect())
+        .map_err(|e| e.to_string())
+}
+
 pub fn read_file_to_string<P>(file_name: P) -> Result<String, std::io::Error>
 where
     P: AsRef<Path>,
 {
+    if is_stdin(file_name.as_ref()) {
+        return read_stdin().map(String::from);
+    }
+
     let mut file_data = String::new();
     match File::open(file_name)?.read_to_string(&mut file_data) {
         Ok(_) => Ok(file_data),
@@ -39,20 +132,137 @@ where
     }
 }
 
+/// The input file named by the first arg, for a day run from its own crate dir. See
+/// [`find_input_file`] for the names it understands.
 pub fn get_input_file_from_args() -> String {
     let mut args = std::env::args();
-    let program_name = args.nth(0).unwrap();
-    let input_file = match args.nth(0).as_ref().map(|s| s.as_str()) {
-        Some("simple") => "src/simple_input.txt",
-        Some("simple2") => "src/simple2_input.txt",
-        Some("real") => "src/input.txt",
-        _ => panic!("USAGE: ./{} [simple|simple2|real]", &program_name),
-    };
+    let program_name = args.next().unwrap();
+    let usage = format!("USAGE: ./{} [real|simple|simple2|...|PATH|-]", &program_name);
+    let input_name = args.next().unwrap_or_else(|| panic!("{}", usage));
+
+    match find_input_file(".", &input_name) {
+        Ok(input_file) => input_file.to_string_lossy().to_string(),
+        Err(e) => panic!("{}\n{}", e, usage),
+    }
+}
+
+/// Resolves an input name (e.g. `real`, `sample`, `sample2`, `simple2`, or a path) to an input file
+/// belonging to the day in `day_dir`. Both the 2020 layout (`src/simple2_input.txt`) and the 2024
+/// layout (`sample_input2.txt` next to the crate) are searched, with `sample` and `simple` treated
+/// as the same thing. `-` is stdin (see [`STDIN_FILE_NAME`]).
+pub fn find_input_file<P>(day_dir: P, input_name: &str) -> Result<std::path::PathBuf, String>
+where
+    P: AsRef<Path>,
+{
+    let day_dir = day_dir.as_ref();
+
+    if input_name == STDIN_FILE_NAME {
+        return Ok(PathBuf::from(STDIN_FILE_NAME));
+    }
+
+    let as_path = Path::new(input_name);
+    if as_path.is_file() {
+        return Ok(as_path.to_path_buf());
+    }
 
-    String::from(input_file)
+    let mut file_names = vec![input_name.to_string(), format!("{}.txt", input_name)];
+
+    let stem = input_name.trim_end_matches(|c: char| c.is_ascii_digit());
+    let number = &input_name[stem.len()..];
+    match stem {
+        "real" | "input" => file_names.push(String::from("input.txt")),
+        "sample" | "simple" => {
+            for alias in ["sample", "simple"] {
+                file_names.push(format!("{}_input{}.txt", alias, number));
+                file_names.push(format!("{}{}_input.txt", alias, number));
+            }
+        }
+        _ => {
+            file_names.push(format!("{}_input{}.txt", stem, number));
+            file_names.push(format!("{}{}_input.txt", stem, number));
+        }
+    }
+
+    for dir in [day_dir.to_path_buf(), day_dir.join("src")] {
+        for file_name in &file_names {
+            let candidate = dir.join(file_name);
+            if candidate.is_file() {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    Err(format!(
+        "No input file found for '{}' in {}",
+        input_name,
+        day_dir.display()
+    ))
 }
 
-pub fn get_nth_string_arg<'a>(args: &'a [String], n: usize) -> Result<&'a str, String> {
+fn is_sample_file(file_name: &str) -> bool {
+    (file_name.starts_with("sample") || file_name.starts_with("simple"))
+        && file_name.contains("_input")
+        && file_name.ends_with(".txt")
+}
+
+/// Sorts `simple_input.txt` before `simple2_input.txt`, and `sample_input2.txt` before
+/// `sample_input10.txt`: by the name without its digits, then by the numbers in it.
+fn sample_sort_key(file_name: &str) -> (String, Vec<u64>) {
+    let text = file_name.replace(|c: char| c.is_ascii_digit(), "");
+    let numbers = file_name
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|digits| !digits.is_empty())
+        .map(|digits| digits.parse().unwrap_or(u64::MAX))
+        .collect();
+
+    (text, numbers)
+}
+
+/// Every sample input of the day in `day_dir` (`sample_input*.txt` or `simple*_input.txt`, in either
+/// layout), in order.
+pub fn find_sample_files<P>(day_dir: P) -> Vec<PathBuf>
+where
+    P: AsRef<Path>,
+{
+    let day_dir = day_dir.as_ref();
+
+    let mut sample_files = vec![];
+    for dir in [day_dir.to_path_buf(), day_dir.join("src")] {
+        let files = match std::fs::read_dir(&dir) {
+            Ok(files) => files,
+            Err(_) => continue,
+        };
+        for file in files.flatten() {
+            let file_name = file.file_name().to_string_lossy().to_string();
+            if is_sample_file(&file_name) && file.path().is_file() {
+                sample_files.push((sample_sort_key(&file_name), file.path()));
+            }
+        }
+    }
+
+    sample_files.sort();
+    sample_files.into_iter().map(|(_, path)| path).collect()
+}
+
+/// Like [`find_input_file`], but `samples` means every sample of the day.
+pub fn find_input_files<P>(day_dir: P, input_name: &str) -> Result<Vec<PathBuf>, String>
+where
+    P: AsRef<Path>,
+{
+    let day_dir = day_dir.as_ref();
+    if input_name != "samples" {
+        return find_input_file(day_dir, input_name).map(|input_file| vec![input_file]);
+    }
+
+    let sample_files = find_sample_files(day_dir);
+    if sample_files.is_empty() {
+        return Err(format!("No sample inputs found in {}", day_dir.display()));
+    }
+
+    Ok(sample_files)
+}
+
+pub fn get_nth_string_arg(args: &[String], n: usize) -> Result<&str, String> {
     if args.len() <= n {
         return Err(format!(
             "Too few args! needed {}; had {}",
@@ -148,4 +358,68 @@ mod tests {
         let no_line = lines.next();
         assert!(no_line.is_none());
     }
+
+    #[test]
+    fn try_read_lines_errors_have_context() {
+        let missing = super::try_read_all_lines("src/missing.txt").unwrap_err();
+        assert!(missing.starts_with("src/missing.txt: "), "{}", missing);
+
+        let bad_file =
+            std::env::temp_dir().join(format!("input_helpers_bad_{}.txt", std::process::id()));
+        std::fs::write(&bad_file, b"ok\n\xff\xfe\n").unwrap();
+        let bad_line = super::try_read_all_lines(&bad_file).unwrap_err();
+        std::fs::remove_file(&bad_file).unwrap();
+        assert!(
+            bad_line.starts_with(&format!("{}:2: ", bad_file.display())),
+            "{}",
+            bad_line
+        );
+    }
+
+    #[test]
+    fn finds_samples_in_order() {
+        let day_dir =
+            std::env::temp_dir().join(format!("input_helpers_samples_{}", std::process::id()));
+        std::fs::create_dir_all(day_dir.join("src")).unwrap();
+        for file_name in [
+            "sample_input10.txt",
+            "sample_input2.txt",
+            "input.txt",
+            "sample_input.txt",
+            "src/simple2_input.txt",
+            "src/simple_input.txt",
+            "src/valid_4.txt",
+        ] {
+            std::fs::write(day_dir.join(file_name), "").unwrap();
+        }
+
+        let samples = super::find_input_files(&day_dir, "samples").unwrap();
+        let real = super::find_input_files(&day_dir, "real").unwrap();
+        std::fs::remove_dir_all(&day_dir).unwrap();
+
+        let sample_names: Vec<String> = samples
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&day_dir)
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(
+            sample_names,
+            [
+                "sample_input.txt",
+                "sample_input2.txt",
+                "sample_input10.txt",
+                "src/simple_input.txt",
+                "src/simple2_input.txt",
+            ]
+        );
+        assert_eq!(real, [day_dir.join("input.txt")]);
+        assert_eq!(
+            super::find_input_file(&day_dir, "-").unwrap(),
+            std::path::Path::new("-")
+        );
+    }
 }
@@ -0,0 +1,344 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Why [`Options::parse`] didn't give back the options.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptionsError {
+    /// `-h` or `--help` asked for the help, which is this. Not a failure, so a runner should print
+    /// it and exit successfully.
+    Help(String),
+    /// Args that don't fit the options, with the reason followed by the help.
+    Invalid(String),
+}
+
+impl std::fmt::Display for OptionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            OptionsError::Help(help) => write!(f, "{}", help),
+            OptionsError::Invalid(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl From<OptionsError> for String {
+    fn from(error: OptionsError) -> String {
+        error.to_string()
+    }
+}
+
+enum OptionKind {
+    Flag { short: Option<char> },
+    Value { default: Option<String> },
+}
+
+struct OptionSpec {
+    name: &'static str,
+    kind: OptionKind,
+    help: &'static str,
+}
+
+impl OptionSpec {
+    fn usage(&self) -> String {
+        match &self.kind {
+            OptionKind::Flag { short: Some(short) } => format!("-{}, --{}", short, self.name),
+            OptionKind::Flag { short: None } => format!("    --{}", self.name),
+            OptionKind::Value { .. } => format!("{}=<value>", self.name),
+        }
+    }
+}
+
+/// The extra args a day understands: flags like `--verbose` (or `-v`) and typed `name=value`
+/// options. Declaring them gives each day a `--help` and an error for args it doesn't know,
+/// instead of silently ignoring a typo.
+///
+/// ```
+/// let options = input_helpers::Options::new()
+///     .flag("verbose", Some('v'), "print each step")
+///     .value("blink_count", 25, "how many times the stones blink");
+///
+/// let args = vec![String::from("-v"), String::from("blink_count=6")];
+/// let parsed = options.parse(&args).unwrap();
+/// assert!(parsed.flag("verbose"));
+/// assert_eq!(parsed.get::<usize>("blink_count").unwrap(), 6);
+/// ```
+#[derive(Default)]
+pub struct Options {
+    specs: Vec<OptionSpec>,
+}
+
+impl Options {
+    pub fn new() -> Options {
+        Options { specs: vec![] }
+    }
+
+    /// A flag given as `--name`, or `-short` if there is one.
+    pub fn flag(mut self, name: &'static str, short: Option<char>, help: &'static str) -> Options {
+        self.specs.push(OptionSpec {
+            name,
+            kind: OptionKind::Flag { short },
+            help,
+        });
+        self
+    }
+
+    /// A `name=value` option that's `default` when it isn't given.
+    pub fn value<T>(mut self, name: &'static str, default: T, help: &'static str) -> Options
+    where
+        T: Display,
+    {
+        self.specs.push(OptionSpec {
+            name,
+            kind: OptionKind::Value {
+                default: Some(default.to_string()),
+            },
+            help,
+        });
+        self
+    }
+
+    /// A `name=value` option that has to be given.
+    pub fn required(mut self, name: &'static str, help: &'static str) -> Options {
+        self.specs.push(OptionSpec {
+            name,
+            kind: OptionKind::Value { default: None },
+            help,
+        });
+        self
+    }
+
+    /// One line per option, with its default.
+    pub fn help(&self) -> String {
+        let usages: Vec<String> = self.specs.iter().map(|spec| spec.usage()).collect();
+        let usage_width = usages.iter().map(|u| u.len()).max().unwrap_or(0);
+
+        let mut help = String::from("options:");
+        for (spec, usage) in self.specs.iter().zip(usages.iter()) {
+            help += &format!("\n  {:<width$}  {}", usage, spec.help, width = usage_width);
+            match &spec.kind {
+                OptionKind::Value {
+                    default: Some(default),
+                } => help += &format!(" (default: {})", default),
+                OptionKind::Value { default: None } => help += " (required)",
+                OptionKind::Flag { .. } => {}
+            }
+        }
+        help += &format!(
+            "\n  {:<width$}  print this help",
+            "-h, --help",
+            width = usage_width
+        );
+
+        help
+    }
+
+    fn find_arg(&self, arg: &str) -> Option<(&OptionSpec, Option<String>)> {
+        if let Some(long_name) = arg.strip_prefix("--") {
+            return self
+                .specs
+                .iter()
+                .find(|spec| matches!(spec.kind, OptionKind::Flag { .. }) && spec.name == long_name)
+                .map(|spec| (spec, None));
+        }
+
+        if let Some(short_name) = arg.strip_prefix('-') {
+            let mut short_chars = short_name.chars();
+            let short_char = match (short_chars.next(), short_chars.next()) {
+                (Some(c), None) => c,
+                _ => return None,
+            };
+            return self
+                .specs
+                .iter()
+                .find(|spec| matches!(spec.kind, OptionKind::Flag { short: Some(s) } if s == short_char))
+                .map(|spec| (spec, None));
+        }
+
+        let (name, value) = arg.split_once('=')?;
+        self.specs
+            .iter()
+            .find(|spec| matches!(spec.kind, OptionKind::Value { .. }) && spec.name == name)
+            .map(|spec| (spec, Some(value.to_string())))
+    }
+
+    /// Parses `args`, returning the args that aren't declared options instead of failing on them.
+    /// For a `main` that takes a few args of its own and passes the rest on to the parts.
+    pub fn parse_known(&self, args: &[String]) -> Result<(ParsedOptions, Vec<String>), String> {
+        let mut parsed = ParsedOptions {
+            names: self.specs.iter().map(|spec| spec.name).collect(),
+            flags: vec![],
+            values: HashMap::new(),
+        };
+        let mut unknown_args = vec![];
+
+        for arg in args {
+            match self.find_arg(arg) {
+                Some((spec, None)) => parsed.flags.push(spec.name),
+                Some((spec, Some(value))) => {
+                    if parsed.values.insert(spec.name, value).is_some() {
+                        return Err(format!("{} was given more than once", spec.name));
+                    }
+                }
+                None => unknown_args.push(arg.clone()),
+            }
+        }
+
+        for spec in &self.specs {
+            if let OptionKind::Value { default } = &spec.kind {
+                if parsed.values.contains_key(spec.name) {
+                    continue;
+                }
+                match default {
+                    Some(default) => {
+                        parsed.values.insert(spec.name, default.clone());
+                    }
+                    None => return Err(format!("Missing {}=<value>\n{}", spec.name, self.help())),
+                }
+            }
+        }
+
+        Ok((parsed, unknown_args))
+    }
+
+    /// Parses `args`, failing on anything that isn't a declared option. `-h` or `--help` comes
+    /// back as [`OptionsError::Help`].
+    pub fn parse(&self, args: &[String]) -> Result<ParsedOptions, OptionsError> {
+        if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+            return Err(OptionsError::Help(self.help()));
+        }
+
+        let (parsed, unknown_args) = self.parse_known(args).map_err(OptionsError::Invalid)?;
+        match unknown_args.first() {
+            Some(unknown_arg) => Err(OptionsError::Invalid(format!(
+                "Unknown arg '{}'\n{}",
+                unknown_arg,
+                self.help()
+            ))),
+            None => Ok(parsed),
+        }
+    }
+}
+
+/// The options found by [`Options::parse`]. Asking for an option that wasn't declared is a bug,
+/// so it panics.
+pub struct ParsedOptions {
+    names: Vec<&'static str>,
+    flags: Vec<&'static str>,
+    values: HashMap<&'static str, String>,
+}
+
+impl ParsedOptions {
+    fn check_declared(&self, name: &str) {
+        assert!(
+            self.names.contains(&name),
+            "option '{}' was never declared",
+            name
+        );
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.check_declared(name);
+        self.flags.contains(&name)
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T, String>
+    where
+        T: FromStr,
+    {
+        self.check_declared(name);
+        let value = &self.values[name];
+        value
+            .parse()
+            .map_err(|_| format!("Invalid value for {}: '{}'", name, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Options, OptionsError};
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn options() -> Options {
+        Options::new()
+            .flag("verbose", Some('v'), "print each step")
+            .value("blink_count", 25, "how many times the stones blink")
+            .required("bytes", "how many bytes fall")
+    }
+
+    #[test]
+    fn parses_flags_values_and_defaults() {
+        let parsed = options()
+            .parse(&to_args(&["--verbose", "bytes=12"]))
+            .unwrap();
+        assert!(parsed.flag("verbose"));
+        assert_eq!(parsed.get::<usize>("blink_count").unwrap(), 25);
+        assert_eq!(parsed.get::<usize>("bytes").unwrap(), 12);
+
+        let parsed = options().parse(&to_args(&["bytes=x"])).unwrap();
+        assert!(!parsed.flag("verbose"));
+        assert_eq!(
+            parsed.get::<usize>("bytes").unwrap_err(),
+            "Invalid value for bytes: 'x'"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_and_missing() {
+        let unknown = options()
+            .parse(&to_args(&["-x", "bytes=12"]))
+            .err()
+            .unwrap();
+        assert!(
+            matches!(&unknown, OptionsError::Invalid(e) if e.starts_with("Unknown arg '-x'\noptions:")),
+            "{:?}",
+            unknown
+        );
+
+        let missing = options().parse(&to_args(&["-v"])).err().unwrap();
+        assert!(
+            matches!(&missing, OptionsError::Invalid(e) if e.starts_with("Missing bytes=<value>")),
+            "{:?}",
+            missing
+        );
+
+        let (parsed, rest) = Options::new()
+            .flag("pt2", Some('2'), "also solve pt 2")
+            .parse_known(&to_args(&["-2", "-v"]))
+            .unwrap();
+        assert!(parsed.flag("pt2"));
+        assert_eq!(rest, to_args(&["-v"]));
+    }
+
+    #[test]
+    fn help_is_told_apart_from_errors() {
+        let help = options().parse(&to_args(&["bytes=1", "--help"])).err();
+        assert_eq!(help, Some(OptionsError::Help(options().help())));
+
+        let no_options = Options::new().parse(&to_args(&["-h"])).err();
+        assert_eq!(
+            no_options,
+            Some(OptionsError::Help(String::from(
+                "options:\n  -h, --help  print this help"
+            )))
+        );
+        assert!(matches!(
+            Options::new().parse(&to_args(&["extra"])),
+            Err(OptionsError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn help_lists_options() {
+        assert_eq!(
+            options().help(),
+            "options:
+  -v, --verbose        print each step
+  blink_count=<value>  how many times the stones blink (default: 25)
+  bytes=<value>        how many bytes fall (required)
+  -h, --help           print this help"
+        );
+    }
+}
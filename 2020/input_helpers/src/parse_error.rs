@@ -0,0 +1,157 @@
+use std::ops::Range;
+use std::str::FromStr;
+
+/// An error in a puzzle input, pointing at the text that couldn't be parsed and saying what was
+/// expected there instead. Converts into the `String` errors the days use, rendered with a caret
+/// under the bad text:
+///
+/// ```text
+/// sample_input.txt:2:4: expected a page number
+///   |
+/// 2 | 47|x3
+///   |    ^^
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// 1-based line number.
+    pub line_number: usize,
+    /// The full text of the offending line.
+    pub line: String,
+    /// Byte range of the offending text within `line`.
+    pub columns: Range<usize>,
+    /// What should have been there, e.g. "a page number".
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line_number: usize,
+        line: &str,
+        columns: Range<usize>,
+        expected: &str,
+    ) -> ParseError {
+        let end = columns.end.min(line.len());
+        let start = columns.start.min(end);
+        ParseError {
+            file: None,
+            line_number,
+            line: line.to_string(),
+            columns: start..end,
+            expected: expected.to_string(),
+        }
+    }
+
+    /// An error covering all of `line`.
+    pub fn whole_line(line_number: usize, line: &str, expected: &str) -> ParseError {
+        ParseError::new(line_number, line, 0..line.len(), expected)
+    }
+
+    /// An error covering `substr`, which must be a slice of `line` (e.g. from `split`). Falls back
+    /// to the whole line if it isn't.
+    pub fn at_substr(line_number: usize, line: &str, substr: &str, expected: &str) -> ParseError {
+        match substr_columns(line, substr) {
+            Some(columns) => ParseError::new(line_number, line, columns, expected),
+            None => ParseError::whole_line(line_number, line, expected),
+        }
+    }
+
+    pub fn with_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    pub fn offending_text(&self) -> &str {
+        &self.line[self.columns.clone()]
+    }
+
+    /// The error message followed by the line, with carets under the offending text.
+    pub fn render(&self) -> String {
+        let column = self.line[..self.columns.start].chars().count();
+        let caret_count = self.offending_text().chars().count().max(1);
+        let line_number_str = self.line_number.to_string();
+        let gutter = " ".repeat(line_number_str.len());
+
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}", file, self.line_number, column + 1),
+            None => format!("{}:{}", self.line_number, column + 1),
+        };
+
+        format!(
+            "{}: expected {}\n{} |\n{} | {}\n{} | {}{}",
+            location,
+            self.expected,
+            gutter,
+            line_number_str,
+            self.line,
+            gutter,
+            " ".repeat(column),
+            "^".repeat(caret_count)
+        )
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for String {
+    fn from(e: ParseError) -> String {
+        e.render()
+    }
+}
+
+fn substr_columns(line: &str, substr: &str) -> Option<Range<usize>> {
+    let line_start = line.as_ptr() as usize;
+    let substr_start = substr.as_ptr() as usize;
+    if substr_start < line_start || substr_start + substr.len() > line_start + line.len() {
+        return None;
+    }
+
+    let start = substr_start - line_start;
+    Some(start..start + substr.len())
+}
+
+/// Parses `substr` (a slice of `line`), pointing the error at it if it doesn't parse.
+pub fn parse_substr<T>(
+    line_number: usize,
+    line: &str,
+    substr: &str,
+    expected: &str,
+) -> Result<T, ParseError>
+where
+    T: FromStr,
+{
+    substr
+        .parse()
+        .map_err(|_| ParseError::at_substr(line_number, line, substr, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    #[test]
+    fn renders_caret_under_bad_text() {
+        let line = "47|x3";
+        let e = super::parse_substr::<usize>(12, line, &line[3..], "a page number")
+            .unwrap_err()
+            .with_file("sample_input.txt");
+
+        assert_eq!(e.offending_text(), "x3");
+        assert_eq!(
+            e.render(),
+            "sample_input.txt:12:4: expected a page number\n   |\n12 | 47|x3\n   |    ^^"
+        );
+    }
+
+    #[test]
+    fn foreign_substr_covers_whole_line() {
+        let e = ParseError::at_substr(1, "abc", "b", "something");
+        assert_eq!(e.columns, 0..3);
+    }
+}
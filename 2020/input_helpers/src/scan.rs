@@ -0,0 +1,204 @@
+use crate::{parse_substr, ParseError};
+use std::str::FromStr;
+
+fn int_spans(line: &str, signed: bool) -> Vec<&str> {
+    let bytes = line.as_bytes();
+    let mut spans = vec![];
+
+    let mut idx = 0;
+    while idx < bytes.len() {
+        if !bytes[idx].is_ascii_digit() {
+            idx += 1;
+            continue;
+        }
+
+        let mut start = idx;
+        if signed && start > 0 && (bytes[start - 1] == b'-' || bytes[start - 1] == b'+') {
+            start -= 1;
+        }
+        while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        spans.push(&line[start..idx]);
+    }
+
+    spans
+}
+
+/// Every run of digits in `line`, ignoring everything in between. Signs are ignored too, so
+/// "1-3" is `[1, 3]`; use [`signed_ints`] when values can be negative.
+pub fn ints<T>(line_number: usize, line: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+{
+    int_spans(line, false)
+        .into_iter()
+        .map(|span| parse_substr(line_number, line, span, "an int that fits"))
+        .collect()
+}
+
+/// Like [`ints`], but a `-` or `+` right before the digits is part of the value, e.g.
+/// "p=0,4 v=3,-3" is `[0, 4, 3, -3]`.
+pub fn signed_ints<T>(line_number: usize, line: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+{
+    int_spans(line, true)
+        .into_iter()
+        .map(|span| parse_substr(line_number, line, span, "an int that fits"))
+        .collect()
+}
+
+/// Matches `literal` against `line` at `start`, returning where the match ends. A space in
+/// `literal` matches any (non-empty) run of whitespace.
+fn match_literal(line: &str, start: usize, literal: &str) -> Option<usize> {
+    let mut pos = start;
+    let mut literal_chars = literal.chars().peekable();
+    while let Some(c) = literal_chars.next() {
+        if c == ' ' {
+            while literal_chars.peek() == Some(&' ') {
+                literal_chars.next();
+            }
+            let whitespace_len = line[pos..].len() - line[pos..].trim_start().len();
+            if whitespace_len == 0 {
+                return None;
+            }
+            pos += whitespace_len;
+        } else if line[pos..].starts_with(c) {
+            pos += c.len_utf8();
+        } else {
+            return None;
+        }
+    }
+
+    Some(pos)
+}
+
+/// Finds the first place at or after `start` where `literal` matches, returning the match's
+/// start and end. With `at_end`, the match also has to end the line.
+fn find_literal(line: &str, start: usize, literal: &str, at_end: bool) -> Option<(usize, usize)> {
+    line[start..]
+        .char_indices()
+        .map(|(idx, _)| start + idx)
+        .chain(std::iter::once(line.len()))
+        .find_map(|match_start| {
+            let match_end = match_literal(line, match_start, literal)?;
+            if at_end && match_end != line.len() {
+                None
+            } else {
+                Some((match_start, match_end))
+            }
+        })
+}
+
+/// Matches `line` against `pattern`, returning the text matched by each `{}`. Everything else in
+/// the pattern has to match exactly, except that a space matches any run of whitespace. A `{}`
+/// matches up to the first place the text after it does, or to the end of the line if it's last.
+///
+/// Usually called through [`scan!`](crate::scan), which also parses the fields.
+pub fn scan_fields<'a>(
+    line_number: usize,
+    line: &'a str,
+    pattern: &str,
+) -> Result<Vec<&'a str>, ParseError> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    let mismatch = |pos: usize| {
+        ParseError::new(
+            line_number,
+            line,
+            pos..line.len(),
+            &format!("a line like '{}'", pattern),
+        )
+    };
+
+    let mut pos = match_literal(line, 0, literals[0])
+        .filter(|match_end| literals.len() > 1 || *match_end == line.len())
+        .ok_or_else(|| mismatch(0))?;
+
+    let mut fields = vec![];
+    for (literal_idx, literal) in literals.iter().enumerate().skip(1) {
+        let is_last = literal_idx == literals.len() - 1;
+        let (field_end, next_pos) = if literal.is_empty() {
+            assert!(is_last, "scan pattern '{}' has two {{}} in a row", pattern);
+            (line.len(), line.len())
+        } else {
+            find_literal(line, pos, literal, is_last).ok_or_else(|| mismatch(pos))?
+        };
+
+        fields.push(&line[pos..field_end]);
+        pos = next_pos;
+    }
+
+    Ok(fields)
+}
+
+/// Scans a line into a tuple of typed values, one per `{}` in the pattern (see [`scan_fields`]).
+/// Errors point at the text that didn't match the pattern or didn't parse.
+///
+/// ```
+/// let (px, py, vx, vy) =
+///     input_helpers::scan!(2, "p=0,4 v=3,-3", "p={},{} v={},{}", usize, usize, isize, isize)
+///         .unwrap();
+/// assert_eq!((px, py, vx, vy), (0, 4, 3, -3));
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($line_number:expr, $line:expr, $pattern:expr, $($t:ty),+ $(,)?) => {
+        (|| -> ::std::result::Result<($($t,)+), $crate::ParseError> {
+            let line_number: usize = $line_number;
+            let line: &str = $line;
+            let fields = $crate::scan_fields(line_number, line, $pattern)?;
+            assert_eq!(
+                fields.len(),
+                [$(stringify!($t)),+].len(),
+                "scan pattern '{}' needs one {{}} per type",
+                $pattern
+            );
+
+            let mut fields = fields.into_iter();
+            Ok(($(
+                $crate::parse_substr::<$t>(
+                    line_number,
+                    line,
+                    fields.next().unwrap(),
+                    concat!("a valid ", stringify!($t)),
+                )?,
+            )+))
+        })()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn extracts_ints() {
+        let line = "Button A: X+94, Y-34";
+        assert_eq!(super::ints::<usize>(1, line).unwrap(), vec![94, 34]);
+        assert_eq!(super::signed_ints::<i32>(1, line).unwrap(), vec![94, -34]);
+
+        let overflow = super::ints::<u8>(3, "1 300").unwrap_err();
+        assert_eq!(overflow.line_number, 3);
+        assert_eq!(overflow.offending_text(), "300");
+    }
+
+    #[test]
+    fn scans_typed_fields() {
+        let (name, value) = crate::scan!(1, "acc  +12", "{} {}", String, i64).unwrap();
+        assert_eq!((name.as_str(), value), ("acc", 12));
+
+        let (x, y) =
+            crate::scan!(1, "Prize: X=8400, Y=5400", "Prize: X={}, Y={}", u64, u64).unwrap();
+        assert_eq!((x, y), (8400, 5400));
+
+        let bad_value =
+            crate::scan!(4, "p=0,x v=3,-3", "p={},{} v={},{}", i32, i32, i32, i32).unwrap_err();
+        assert_eq!(bad_value.line_number, 4);
+        assert_eq!(bad_value.offending_text(), "x");
+        assert_eq!(bad_value.expected, "a valid i32");
+
+        let bad_literal =
+            crate::scan!(1, "p=0,4 w=3,-3", "p={},{} v={},{}", i32, i32, i32, i32).unwrap_err();
+        assert_eq!(bad_literal.offending_text(), "4 w=3,-3");
+        assert_eq!(bad_literal.expected, "a line like 'p={},{} v={},{}'");
+    }
+}
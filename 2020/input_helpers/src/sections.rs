@@ -0,0 +1,168 @@
+use crate::ParseError;
+use std::convert::TryInto;
+
+/// A block of non-blank lines from an input, remembering where it started so parse errors can
+/// point at the right line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Section<'a> {
+    /// 1-based line number of the section's first line.
+    pub first_line_number: usize,
+    pub lines: &'a [String],
+}
+
+impl<'a> Section<'a> {
+    /// The section's lines paired with their 1-based line numbers.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a String)> + 'a {
+        let first_line_number = self.first_line_number;
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(idx, line)| (first_line_number + idx, line))
+    }
+
+    /// The rest of the section after its first line, which must be `header` (e.g. "your ticket:").
+    pub fn strip_header(&self, header: &str) -> Result<Section<'a>, ParseError> {
+        // Sections are never empty, so there's always a first line
+        if self.lines[0] != header {
+            return Err(ParseError::whole_line(
+                self.first_line_number,
+                &self.lines[0],
+                &format!("'{}'", header),
+            ));
+        }
+
+        Ok(Section {
+            first_line_number: self.first_line_number + 1,
+            lines: &self.lines[1..],
+        })
+    }
+}
+
+/// Iterator over the [`Section`]s of some lines. See [`sections`].
+pub struct Sections<'a> {
+    lines: &'a [String],
+    line_idx: usize,
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Section<'a>> {
+        while self.line_idx < self.lines.len() && is_blank(&self.lines[self.line_idx]) {
+            self.line_idx += 1;
+        }
+        if self.line_idx == self.lines.len() {
+            return None;
+        }
+
+        let start = self.line_idx;
+        while self.line_idx < self.lines.len() && !is_blank(&self.lines[self.line_idx]) {
+            self.line_idx += 1;
+        }
+
+        Some(Section {
+            first_line_number: start + 1,
+            lines: &self.lines[start..self.line_idx],
+        })
+    }
+}
+
+/// Splits `lines` into blocks separated by blank lines. Runs of blank lines (and blank lines at
+/// the start or end) count as one separator, so no section is ever empty.
+pub fn sections(lines: &[String]) -> Sections<'_> {
+    Sections { lines, line_idx: 0 }
+}
+
+/// Splits `lines` into exactly `N` sections, where `descs` says what each one holds (e.g. "the
+/// rules") for the error when there are too few or too many.
+pub fn exact_sections<'a, const N: usize>(
+    lines: &'a [String],
+    descs: [&str; N],
+) -> Result<[Section<'a>; N], ParseError> {
+    let mut found = sections(lines);
+    let mut found_sections = Vec::with_capacity(N);
+    for desc in descs.iter() {
+        match found.next() {
+            Some(section) => found_sections.push(section),
+            None if found_sections.is_empty() => {
+                return Err(ParseError::whole_line(lines.len() + 1, "", desc));
+            }
+            None => {
+                return Err(ParseError::whole_line(
+                    lines.len() + 1,
+                    "",
+                    &format!("an empty line followed by {}", desc),
+                ));
+            }
+        }
+    }
+
+    if let Some(extra) = found.next() {
+        return Err(ParseError::whole_line(
+            extra.first_line_number,
+            &extra.lines[0],
+            &format!("the end of the input after {}", descs[N - 1]),
+        ));
+    }
+
+    match found_sections.try_into() {
+        Ok(found_sections) => Ok(found_sections),
+        Err(_) => unreachable!("exactly N sections were collected"),
+    }
+}
+
+/// The common two part input, e.g. rules then updates.
+pub fn two_sections<'a>(
+    lines: &'a [String],
+    first_desc: &str,
+    second_desc: &str,
+) -> Result<(Section<'a>, Section<'a>), ParseError> {
+    let [first, second] = exact_sections(lines, [first_desc, second_desc])?;
+    Ok((first, second))
+}
+
+#[cfg(test)]
+mod tests {
+    fn to_lines(input: &str) -> Vec<String> {
+        input.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn splits_on_runs_of_blank_lines() {
+        let lines = to_lines("\na\nb\n\n\nc\n  \nd\n");
+        let found: Vec<(usize, Vec<&str>)> = super::sections(&lines)
+            .map(|s| {
+                (
+                    s.first_line_number,
+                    s.lines.iter().map(|l| l.as_str()).collect(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![(2, vec!["a", "b"]), (6, vec!["c"]), (8, vec!["d"])]
+        );
+    }
+
+    #[test]
+    fn two_sections_reports_missing_and_extra() {
+        let lines = to_lines("1|2\n\n1,2");
+        let (rules, updates) = super::two_sections(&lines, "the rules", "the updates").unwrap();
+        assert_eq!(rules.lines, &lines[..1]);
+        assert_eq!(updates.first_line_number, 3);
+
+        let missing = super::two_sections(&lines[..1], "the rules", "the updates").unwrap_err();
+        assert_eq!(missing.line_number, 2);
+        assert_eq!(missing.expected, "an empty line followed by the updates");
+
+        let lines = to_lines("1|2\n\n1,2\n\n3");
+        let extra = super::two_sections(&lines, "the rules", "the updates").unwrap_err();
+        assert_eq!(extra.line_number, 5);
+        assert_eq!(extra.offending_text(), "3");
+    }
+}
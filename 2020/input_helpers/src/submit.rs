@@ -0,0 +1,229 @@
+use crate::fetch::{Fetcher, USER_AGENT};
+use std::time::Duration;
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    /// Wrong answers come with a cooldown before the next one is allowed (e.g. "Please wait one
+    /// minute before trying again"), which is `None` if the page didn't say.
+    TooHigh(Option<Duration>),
+    TooLow(Option<Duration>),
+    /// Wrong, with no hint about which way.
+    Wrong(Option<Duration>),
+    AlreadySolved,
+    /// An answer was sent too recently; nothing was checked. The wait is `None` if the page didn't
+    /// say how long it is.
+    RateLimited(Option<Duration>),
+    /// A page we don't know how to read, with its main text.
+    Unrecognized(String),
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh(cooldown) => write!(f, "too high{}", cooldown_note(cooldown)),
+            Verdict::TooLow(cooldown) => write!(f, "too low{}", cooldown_note(cooldown)),
+            Verdict::Wrong(cooldown) => write!(f, "wrong{}", cooldown_note(cooldown)),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::RateLimited(Some(wait)) => {
+                write!(f, "rate limited ({}s left)", wait.as_secs())
+            }
+            Verdict::RateLimited(None) => write!(f, "rate limited"),
+            Verdict::Unrecognized(text) => write!(f, "unrecognized response: {}", text),
+        }
+    }
+}
+
+fn cooldown_note(cooldown: &Option<Duration>) -> String {
+    match cooldown {
+        Some(cooldown) => format!(" (wait {}s before trying again)", cooldown.as_secs()),
+        None => String::new(),
+    }
+}
+
+impl Fetcher {
+    pub fn answer_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, year, day)
+    }
+
+    /// Submits `answer` for `part` (1 or 2) of `year`/`day` and reads the verdict from the page
+    /// that comes back.
+    pub fn submit_answer(
+        &self,
+        year: u32,
+        day: u32,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, String> {
+        let url = self.answer_url(year, day);
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map(|page| parse_verdict(&page))
+                .map_err(|e| format!("Failed to read response from {}: {}", url, e)),
+            Err(ureq::Error::Status(400, _)) => Err(format!(
+                "{} rejected the session cookie; is it out of date?",
+                url
+            )),
+            Err(e) => Err(format!("Failed to submit answer: {}", e)),
+        }
+    }
+}
+
+/// Reads the verdict out of the page returned after submitting an answer. Only the `<article>`
+/// is looked at, since the rest of the page is the site's header and footer.
+pub fn parse_verdict(page: &str) -> Verdict {
+    let text = strip_tags(article(page));
+
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        let cooldown = parse_cooldown(&text);
+        if text.contains("too high") {
+            Verdict::TooHigh(cooldown)
+        } else if text.contains("too low") {
+            Verdict::TooLow(cooldown)
+        } else {
+            Verdict::Wrong(cooldown)
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::RateLimited(parse_wait(&text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unrecognized(text)
+    }
+}
+
+fn article(page: &str) -> &str {
+    let start = match page.find("<article") {
+        Some(start) => start,
+        None => return page,
+    };
+    let end = page[start..]
+        .find("</article>")
+        .map(|end| start + end)
+        .unwrap_or(page.len());
+    &page[start..end]
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Reads the wait out of e.g. "You have 1m 23s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for amount in text[start..end].split_whitespace() {
+        let unit_idx = amount.find(|c: char| !c.is_ascii_digit())?;
+        let value: u64 = amount[..unit_idx].parse().ok()?;
+        secs += match &amount[unit_idx..] {
+            "h" => value * 60 * 60,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+/// Reads the cooldown out of e.g. "Please wait one minute before trying again." or "please wait
+/// 5 minutes before trying again."
+fn parse_cooldown(text: &str) -> Option<Duration> {
+    let start = text.find("wait ")? + "wait ".len();
+    let mut words = text[start..].split_whitespace();
+    let amount = words.next()?;
+    let unit = words.next()?;
+    if words.next() != Some("before") {
+        return None;
+    }
+
+    const NUMBER_WORDS: [&str; 10] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+    let value: u64 = match NUMBER_WORDS.iter().position(|word| *word == amount) {
+        Some(idx) => idx as u64 + 1,
+        None => amount.parse().ok()?,
+    };
+    match unit.trim_end_matches('s') {
+        "hour" => Some(Duration::from_secs(value * 60 * 60)),
+        "minute" => Some(Duration::from_secs(value * 60)),
+        "second" => Some(Duration::from_secs(value)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_verdict, Verdict};
+    use std::time::Duration;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><header>You have 2 stars</header><main><article><p>{}</p></article></main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn reads_verdicts() {
+        assert_eq!(
+            parse_verdict(&page(
+                "That's the right answer!  You are <span>one gold star</span> closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too high.  Please wait one minute before trying again."
+            )),
+            Verdict::TooHigh(Some(Duration::from_secs(60)))
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow(None)
+        );
+        assert_eq!(
+            parse_verdict(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.")),
+            Verdict::Wrong(Some(Duration::from_secs(300)))
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            parse_verdict(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.")),
+            Verdict::RateLimited(Some(Duration::from_secs(83)))
+        );
+        assert_eq!(
+            parse_verdict(&page("Something new")),
+            Verdict::Unrecognized(String::from("Something new"))
+        );
+    }
+}
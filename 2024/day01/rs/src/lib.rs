@@ -0,0 +1,91 @@
+use input_helpers;
+
+pub type InputPair = (isize, isize);
+
+fn read_input_pairs_from_file(filename: &str) -> Result<Vec<InputPair>, String> {
+    let lines = input_helpers::try_read_lines(filename).map_err(|e| e.to_string())?;
+    let mut pairs: Vec<InputPair> = Vec::new();
+    for line in lines {
+        let line = line.map_err(|e| e.to_string())?;
+        let values: Vec<&str> = line.split_ascii_whitespace().collect();
+        if values.len() != 2 {
+            return Err(format!(
+                "Invalid number of values on line. Expected 2 found {}. line={}",
+                values.len(),
+                line
+            ));
+        }
+
+        fn parse_pair_value(v: &str, line: &str) -> Result<isize, String> {
+            match v.parse() {
+                Ok(value) => Ok(value),
+                Err(e) => Err(format!("Invalid value: {}! '{}' line={}", e, v, line)),
+            }
+        }
+
+        let v1 = parse_pair_value(values[0], &line)?;
+        let v2 = parse_pair_value(values[1], &line)?;
+        pairs.push((v1, v2));
+    }
+
+    Ok(pairs)
+}
+
+fn calculate_total_input_pair_distance(input_pairs: &[InputPair]) -> usize {
+    let mut first_list: Vec<isize> = input_pairs.iter().map(|(v1, _)| *v1).collect();
+    first_list.sort();
+    let first_list = first_list;
+
+    let mut second_list: Vec<isize> = input_pairs.iter().map(|(_, v2)| *v2).collect();
+    second_list.sort();
+    let second_list = second_list;
+
+    let mut total_dist = 0;
+    for (v1, v2) in first_list.iter().zip(second_list.iter()) {
+        let dist = (v1 - v2).abs() as usize;
+        total_dist += dist;
+    }
+
+    total_dist
+}
+
+fn calculate_similarity_score(input_pairs: &[InputPair]) -> usize {
+    let mut second_list_counts = std::collections::HashMap::<isize, usize>::new();
+    for (_, v2) in input_pairs {
+        if let Some(v) = second_list_counts.get_mut(v2) {
+            *v += 1;
+        } else {
+            second_list_counts.insert(*v2, 1);
+        }
+    }
+
+    let mut total_similarity_score = 0;
+    for (v1, _) in input_pairs.iter().cloned() {
+        let similarity_score = (v1 as usize) * second_list_counts.get(&v1).unwrap_or(&0);
+        total_similarity_score += similarity_score;
+    }
+
+    total_similarity_score
+}
+
+pub struct Day01;
+
+impl solution::Solution for Day01 {
+    type Input = Vec<InputPair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_input_pairs_from_file(filename)
+    }
+
+    fn part1(input_pairs: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+        Ok(calculate_total_input_pair_distance(input_pairs))
+    }
+
+    fn part2(input_pairs: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+        Ok(calculate_similarity_score(input_pairs))
+    }
+}
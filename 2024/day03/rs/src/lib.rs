@@ -0,0 +1,102 @@
+use input_helpers;
+
+pub type MemoryLine = String;
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum ProcessorState {
+    OpsEnabled,
+    OpsDisabled,
+}
+
+type MulOp = (isize, isize, ProcessorState);
+
+fn read_memory_line(filename: &str) -> Result<MemoryLine, String> {
+    match input_helpers::read_file_to_string(filename) {
+        Ok(data) => Ok(data),
+        Err(e) => Err(format!("Failed to read {}! {}", filename, e)),
+    }
+}
+
+fn extract_mul_ops(memory_line: &str) -> Vec<MulOp> {
+    let re = regex::Regex::new(r"(don't)|(do)|(mul\((\d{1,3}),(\d{1,3})\))").unwrap();
+    let mut results: Vec<MulOp> = vec![];
+    let mut processor_state = ProcessorState::OpsEnabled;
+    for caps in re.captures_iter(memory_line) {
+        // "don't" capture group
+        if caps.get(1).is_some() {
+            processor_state = ProcessorState::OpsDisabled;
+        }
+        // "do" capture group
+        else if caps.get(2).is_some() {
+            processor_state = ProcessorState::OpsEnabled;
+        }
+        // "mul(X,Y)" capture group
+        else {
+            let op1: isize = caps.get(4).unwrap().as_str().parse().unwrap();
+            let op2: isize = caps.get(5).unwrap().as_str().parse().unwrap();
+            results.push((op1, op2, processor_state));
+        }
+    }
+    results
+}
+
+pub struct Day03;
+
+impl solution::Solution for Day03 {
+    type Input = MemoryLine;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_memory_line(filename)
+    }
+
+    fn part1(memory_line: &Self::Input, args: &[String]) -> Result<isize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let start_time = std::time::Instant::now();
+
+        let mul_ops = extract_mul_ops(memory_line);
+        println!(
+            "EXTRACT TIME: ({:0.06}s)",
+            start_time.elapsed().as_secs_f64()
+        );
+
+        // for mul_op in mul_ops.iter() {
+        //     println!("+ ({} * {})", mul_op.0, mul_op.1)
+        // }
+        let mul_sum = mul_ops
+            .iter()
+            .map(|mul_op| mul_op.0 * mul_op.1)
+            .fold(0, |acc, v| acc + v);
+
+        println!("TIME: ({:0.06}s)", start_time.elapsed().as_secs_f64());
+        Ok(mul_sum)
+    }
+
+    fn part2(memory_line: &Self::Input, args: &[String]) -> Result<isize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let start_time = std::time::Instant::now();
+
+        let mul_ops = extract_mul_ops(memory_line);
+        println!(
+            "EXTRACT TIME: ({:0.06}s)",
+            start_time.elapsed().as_secs_f64()
+        );
+
+        // for mul_op in mul_ops.iter() {
+        //     if mul_op.2 == ProcessorState::OpsEnabled {
+        //         println!("+ ({} * {})", mul_op.0, mul_op.1)
+        //     }
+        // }
+        let filtered_mul_sum = mul_ops
+            .iter()
+            .filter(|mul_op| mul_op.2 == ProcessorState::OpsEnabled)
+            .map(|mul_op| mul_op.0 * mul_op.1)
+            .fold(0, |acc, v| acc + v);
+
+        println!("TIME: ({:0.06}s)", start_time.elapsed().as_secs_f64());
+        Ok(filtered_mul_sum)
+    }
+}
@@ -0,0 +1,55 @@
+use input_helpers;
+use simple_grid::search::{self, Stencil, StencilMatch, WordMatch};
+use simple_grid::{Dir8, Orientation};
+
+pub type Grid = simple_grid::Grid<char>;
+
+fn read_grid(filename: &str) -> Result<Grid, String> {
+    let text = input_helpers::read_file_to_string(filename).map_err(|e| format!("{}: {}", filename, e))?;
+    let grid = Grid::parse(&text, |c, _pos| Ok::<_, String>(c)).map_err(|e| e.with_file(filename))?;
+
+    Ok(grid)
+}
+
+fn find_pt1_word_search_solutions(grid: &Grid) -> Vec<WordMatch> {
+    let xmas: Vec<char> = "XMAS".chars().collect();
+    search::find_word(grid, &xmas, &Dir8::ALL)
+}
+
+fn find_pt2_word_search_solutions(grid: &Grid) -> Vec<StencilMatch> {
+    // Two "MAS"es crossed over the 'A', turned any way round
+    let x_mas = Stencil::parse("M.S\n.A.\nM.S", '.').unwrap();
+    search::find_stencil(grid, &x_mas, &Orientation::ALL)
+}
+
+pub struct Day04;
+
+impl solution::Solution for Day04 {
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_grid(filename)
+    }
+
+    fn part1(grid: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let pt1_start_time = std::time::Instant::now();
+        let pt1_solutions = find_pt1_word_search_solutions(grid);
+        let pt1_time = pt1_start_time.elapsed();
+        println!("TIME: ({:0.06}s)", pt1_time.as_secs_f64());
+        Ok(pt1_solutions.len())
+    }
+
+    fn part2(grid: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let pt2_start_time = std::time::Instant::now();
+        let pt2_solutions = find_pt2_word_search_solutions(grid);
+        let pt2_time = pt2_start_time.elapsed();
+        println!("TIME: ({:0.06}s)", pt2_time.as_secs_f64());
+        Ok(pt2_solutions.len())
+    }
+}
@@ -0,0 +1,208 @@
+use input_helpers::{parse_substr, ParseError};
+
+type UpdateRuleSet = std::collections::HashMap<usize, std::collections::HashSet<usize>>;
+
+type ManualUpdate = Vec<usize>;
+
+pub struct ManualUpdateRequest {
+    rules: UpdateRuleSet,
+    updates: Vec<ManualUpdate>,
+}
+
+fn read_manual_update_request(filename: &str) -> Result<ManualUpdateRequest, String> {
+    let lines: Vec<String> = input_helpers::try_read_all_lines(filename)?;
+    parse_manual_update_request(&lines).map_err(|e| e.with_file(filename).into())
+}
+
+fn parse_manual_update_request(lines: &[String]) -> Result<ManualUpdateRequest, ParseError> {
+    let (rule_section, update_section) =
+        input_helpers::two_sections(lines, "the page ordering rules", "the updates")?;
+
+    let mut rules = UpdateRuleSet::new();
+    for (line_number, rule_line) in rule_section.numbered_lines() {
+        let (before_page_str, follow_page_str) = rule_line.split_once('|').ok_or_else(|| {
+            ParseError::whole_line(line_number, rule_line, "a rule like '<page>|<page>'")
+        })?;
+        let before_page: usize =
+            parse_substr(line_number, rule_line, before_page_str, "a page number")?;
+        let follow_page: usize =
+            parse_substr(line_number, rule_line, follow_page_str, "a page number")?;
+
+        if let Some(follow_pages) = rules.get_mut(&before_page) {
+            follow_pages.insert(follow_page);
+        } else {
+            let mut follow_pages = std::collections::HashSet::new();
+            follow_pages.insert(follow_page);
+            rules.insert(before_page, follow_pages);
+        }
+    }
+
+    let mut updates: Vec<ManualUpdate> = Vec::with_capacity(update_section.lines.len());
+    for (line_number, update_line) in update_section.numbered_lines() {
+        let update: ManualUpdate = update_line
+            .split(',')
+            .map(|page_num_str| {
+                parse_substr(line_number, update_line, page_num_str, "a page number")
+            })
+            .collect::<Result<_, _>>()?;
+        updates.push(update);
+    }
+
+    Ok(ManualUpdateRequest { rules, updates })
+}
+
+fn is_update_in_correct_order(rules: &UpdateRuleSet, update: &ManualUpdate) -> bool {
+    for (i, page) in update.iter().enumerate() {
+        // For each page in the update, check all pages before it and see if any rules
+        // would be violated by those pages
+        if let Some(follow_pages) = rules.get(&page) {
+            for page_before in &update[..i] {
+                if follow_pages.contains(page_before) {
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
+
+fn correct_update_ordering(rules: &UpdateRuleSet, update: &ManualUpdate) -> ManualUpdate {
+    let mut new_update = Vec::with_capacity(update.len());
+
+    for page in update {
+        let queried_insert_position: Option<usize> = if let Some(follow_pages) = rules.get(page) {
+            new_update
+                .iter()
+                .position(|new_update_page| follow_pages.contains(new_update_page))
+        } else {
+            None
+        };
+
+        match queried_insert_position {
+            Some(insert_pos) => new_update.insert(insert_pos, *page),
+            None => new_update.push(*page),
+        }
+    }
+
+    new_update
+}
+
+fn find_correctly_ordered_updates(
+    manual_update_request: &ManualUpdateRequest,
+) -> Vec<(usize, ManualUpdate)> {
+    manual_update_request
+        .updates
+        .iter()
+        .enumerate()
+        .filter(|(_i, update)| is_update_in_correct_order(&manual_update_request.rules, update))
+        .map(|(i, update)| (i, update.clone()))
+        .collect()
+}
+
+pub struct Day05;
+
+impl solution::Solution for Day05 {
+    type Input = ManualUpdateRequest;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_manual_update_request(filename)
+    }
+
+    fn part1(manual_update_request: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let pt1_start_time = std::time::Instant::now();
+
+        let correctly_ordered_updates = find_correctly_ordered_updates(manual_update_request);
+
+        let middle_page_sum: usize = correctly_ordered_updates
+            .iter()
+            .map(|(_i, update)| update[update.len() / 2])
+            .sum();
+
+        let pt1_time = pt1_start_time.elapsed();
+
+        println!(
+            "{} updates correctly ordered",
+            correctly_ordered_updates.len()
+        );
+
+        // Only log more details about the updates when it won't bloat our output.
+        // Useful for checking the sample_input.txt
+        if correctly_ordered_updates.len() < 10 {
+            for (update_idx, update) in &correctly_ordered_updates {
+                println!(
+                    " - Update {:03} is correctly ordered! {:?}",
+                    update_idx, update
+                );
+            }
+        }
+
+        println!("TIME: ({:0.06}s)", pt1_time.as_secs_f64());
+
+        Ok(middle_page_sum)
+    }
+
+    fn part2(manual_update_request: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let pt2_start_time = std::time::Instant::now();
+
+        let correctly_ordered_updates = find_correctly_ordered_updates(manual_update_request);
+
+        let incorrectly_ordered_updates: Vec<(usize, ManualUpdate)> = manual_update_request
+            .updates
+            .iter()
+            .enumerate()
+            .filter(|(i, _update)| {
+                !correctly_ordered_updates
+                    .iter()
+                    .any(|(j, _correct_update)| i == j)
+            })
+            .map(|(i, update)| (i, update.clone()))
+            .collect();
+
+        let corrected_updates: Vec<ManualUpdate> = incorrectly_ordered_updates
+            .iter()
+            .map(|(_i, update)| correct_update_ordering(&manual_update_request.rules, update))
+            .collect();
+
+        let corrections_middle_page_sum: usize = corrected_updates
+            .iter()
+            .map(|update| update[update.len() / 2])
+            .sum();
+
+        let pt2_time = pt2_start_time.elapsed();
+
+        println!(
+            "{} updates incorrectly ordered",
+            incorrectly_ordered_updates.len()
+        );
+
+        // Only log more details about the updates when it won't bloat our output.
+        // Useful for checking the sample_input.txt
+        if incorrectly_ordered_updates.len() < 10 {
+            for ((update_idx, incorrect_update), corrected_update) in incorrectly_ordered_updates
+                .iter()
+                .zip(corrected_updates.iter())
+            {
+                println!(
+                    " - Update {:03} incorrect: {:?}",
+                    update_idx, incorrect_update
+                );
+                println!(
+                    " - Update {:03} corrected: {:?}",
+                    update_idx, corrected_update
+                );
+                println!("");
+            }
+        }
+
+        println!("TIME: ({:0.06}s)", pt2_time.as_secs_f64());
+
+        Ok(corrections_middle_page_sum)
+    }
+}
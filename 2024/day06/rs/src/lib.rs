@@ -0,0 +1,218 @@
+use input_helpers;
+use simple_grid::{Dir4, GridPos};
+
+type Grid = simple_grid::Grid<Cell>;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Obstacle,
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct PlayerState {
+    pos: GridPos,
+    dir: Dir4,
+}
+
+fn read_starting_board_state(filename: &str) -> Result<(Grid, PlayerState), String> {
+    let text = input_helpers::read_file_to_string(filename).map_err(|e| format!("{}: {}", filename, e))?;
+
+    let mut player_state: Option<PlayerState> = None;
+    let grid = Grid::parse(&text, |c, pos| {
+        let dir = match c {
+            '.' => return Ok(Cell::Empty),
+            '#' => return Ok(Cell::Obstacle),
+            '^' => Dir4::N,
+            '>' => Dir4::E,
+            'v' => Dir4::S,
+            '<' => Dir4::W,
+            _ => return Err("'.', '#' or the guard ('^', '>', 'v' or '<')"),
+        };
+        if player_state.is_some() {
+            return Err("only one guard");
+        }
+        player_state = Some(PlayerState{pos, dir});
+        Ok(Cell::Empty)
+    })
+    .map_err(|e| e.with_file(filename))?;
+
+    if let Some(player_state) = player_state {
+        Ok((grid, player_state))
+    } else {
+        Err(format!("{}: Did not find player position in input", filename))
+    }
+}
+
+fn simulate_board_step(grid: &Grid, player_state: &PlayerState) -> PlayerState {
+    let next_player_position = player_state.pos + player_state.dir;
+
+    if grid.is_pos_out_of_bounds(next_player_position.row, next_player_position.col) {
+        PlayerState {pos: next_player_position, dir: player_state.dir }
+    } 
+    else {
+        match grid.get_cell(next_player_position.row, next_player_position.col) {
+            Cell::Empty => {
+                PlayerState {pos: next_player_position, dir: player_state.dir }
+            },
+            Cell::Obstacle => {
+                PlayerState {pos: player_state.pos, dir: player_state.dir.turn_cw() }
+            },
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn print_board_state(grid: &Grid, player_state: &PlayerState) {
+    for r in 0..(grid.height as isize) {
+        for c in 0..(grid.width as isize) {
+            if r == player_state.pos.row && c == player_state.pos.col {
+                let player_char = match player_state.dir {
+                    Dir4::N => '^',
+                    Dir4::E => '>',
+                    Dir4::S => 'v',
+                    Dir4::W => '<',
+                };
+                print!("{}", player_char);
+            } else {
+                let cell_char = match grid.get_cell(r, c) {
+                    Cell::Empty => '.',
+                    Cell::Obstacle => '#',
+                };
+                print!("{}", cell_char);
+            }
+        }
+        println!();
+    }
+    println!();
+}
+
+#[allow(dead_code)]
+fn print_move_history(grid: &Grid, move_history: &std::collections::HashSet<GridPos>) {
+    for r in 0..(grid.height as isize) {
+        for c in 0..(grid.width as isize) {
+            if move_history.contains(&GridPos{row: r, col: c}) {
+                print!("X");
+            } else {
+                let cell_char = match grid.get_cell(r, c) {
+                    Cell::Empty => '.',
+                    Cell::Obstacle => '#',
+                };
+                print!("{}", cell_char);
+            }
+        }
+        println!();
+    }
+    println!();
+}
+
+fn test_for_simulation_loop(grid: &Grid, player_initial_state: &PlayerState) -> bool {
+
+    let mut player_state = player_initial_state.clone();
+    let mut player_state_history = std::collections::HashSet::new();
+
+    //print_board_state(&grid, &player_state);
+
+    while !grid.is_pos_out_of_bounds(player_state.pos.row, player_state.pos.col) {
+        if player_state_history.contains(&player_state) {
+            return true;
+        }
+
+        player_state_history.insert(player_state);
+        player_state = simulate_board_step(&grid, &player_state);
+        //print_board_state(&grid, &player_state);
+    }
+
+    false
+}
+
+fn find_player_space_history(
+    grid: &Grid,
+    player_initial_state: &PlayerState,
+) -> std::collections::HashSet<GridPos> {
+    let mut player_state = player_initial_state.clone();
+    let mut player_space_history = std::collections::HashSet::new();
+
+    //print_board_state(&grid, &player_state);
+
+    loop {
+        player_space_history.insert(player_state.pos);
+
+        player_state = simulate_board_step(&grid, &player_state);
+        //print_board_state(&grid, &player_state);
+
+        if grid.is_pos_out_of_bounds(player_state.pos.row, player_state.pos.col) {
+            break;
+        }
+    }
+
+    player_space_history
+}
+
+pub struct Day06;
+
+impl solution::Solution for Day06 {
+    type Input = (Grid, PlayerState);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_starting_board_state(filename)
+    }
+
+    fn part1((grid, player_initial_state): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let pt1_start_time = std::time::Instant::now();
+
+        let player_space_history = find_player_space_history(grid, player_initial_state);
+
+        let pt1_time = pt1_start_time.elapsed();
+
+        //print_move_history(grid, &player_space_history);
+
+        println!("TIME: ({:0.06}s)", pt1_time.as_secs_f64());
+
+        Ok(player_space_history.len())
+    }
+
+    fn part2((grid, player_initial_state): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let pt2_start_time = std::time::Instant::now();
+
+        let player_space_history = find_player_space_history(grid, player_initial_state);
+
+        let mut looping_obstructions: Vec<GridPos> = Vec::new();
+
+        let obstruction_candidates = {
+            let mut obstruction_candidates = player_space_history.clone();
+            obstruction_candidates.remove(&player_initial_state.pos);
+            obstruction_candidates
+        };
+
+        for visited_player_space in obstruction_candidates {
+            let mut obstructed_grid = Grid { width: grid.width, height: grid.height, cells: grid.cells.clone() };
+            {
+                let cell_ref = obstructed_grid.get_cell_mut(visited_player_space.row, visited_player_space.col);
+                assert!(*cell_ref == Cell::Empty);
+                *cell_ref = Cell::Obstacle;
+            }
+
+            let obstruction_loops_player = test_for_simulation_loop(&obstructed_grid, player_initial_state);
+            if obstruction_loops_player {
+                looping_obstructions.push(visited_player_space);
+            }
+
+            // println!("Placing obstruction at (r:{},c:{}) looped player? {}",
+            //     visited_player_space.row,
+            //     visited_player_space.col,
+            //     obstruction_loops_player);
+        }
+
+        let pt2_time = pt2_start_time.elapsed();
+        println!("TIME: ({:0.06}s)", pt2_time.as_secs_f64());
+
+        Ok(looping_obstructions.len())
+    }
+}
@@ -0,0 +1,227 @@
+use input_helpers::{parse_substr, ParseError};
+
+#[derive(Debug, Clone)]
+pub struct Equation {
+    result: usize,
+    operands: Vec<usize>,
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add,
+    Mul,
+    Concat,
+}
+
+fn read_equations(filename: &str) -> Result<Vec<Equation>, String> {
+    let lines: Vec<String> = input_helpers::try_read_all_lines(filename)?;
+
+    let mut equations = vec![];
+    for (line_idx, line) in lines.iter().enumerate() {
+        let eq = parse_equation_from_line(line_idx + 1, line)
+            .map_err(|e| e.with_file(filename))?;
+        equations.push(eq);
+    }
+
+    Ok(equations)
+}
+
+fn parse_equation_from_line(line_number: usize, line: &str) -> Result<Equation, ParseError> {
+    let (result_str, operands_str) = line.split_once(": ").ok_or_else(|| {
+        ParseError::whole_line(line_number, line, "an equation like '<result>: <operands>'")
+    })?;
+
+    if operands_str.is_empty() {
+        return Err(ParseError::new(
+            line_number,
+            line,
+            line.len()..line.len(),
+            "operands after the separator",
+        ));
+    }
+
+    let result: usize = parse_substr(line_number, line, result_str, "a result value")?;
+    let mut operands: Vec<usize> = vec![];
+    for operand_str in operands_str.split_ascii_whitespace() {
+        let operand = parse_substr(line_number, line, operand_str, "an operand value")?;
+        operands.push(operand);
+    }
+
+    Ok(Equation { result, operands })
+}
+
+fn solve_recursive_any_pt1(equation: &Equation) -> Option<Vec<Operation>> {
+    fn solve_recursive_any_helper(
+        exp_result: usize,
+        curr_value: usize,
+        operands_left: &[usize],
+    ) -> Option<Vec<Operation>> {
+        if operands_left.len() == 0 {
+            return if exp_result == curr_value {
+                Some(vec![])
+            } else {
+                None
+            };
+        }
+
+        let next_rhs_op: usize = operands_left[0];
+        let next_add_candidate = curr_value + next_rhs_op;
+        if let Some(mut solved_operation_list) =
+            solve_recursive_any_helper(exp_result, next_add_candidate, &operands_left[1..])
+        {
+            solved_operation_list.push(Operation::Add);
+            Some(solved_operation_list)
+        } else {
+            let next_mul_candidate = curr_value * next_rhs_op;
+            if let Some(mut solved_operation_list) =
+                solve_recursive_any_helper(exp_result, next_mul_candidate, &operands_left[1..])
+            {
+                solved_operation_list.push(Operation::Mul);
+                Some(solved_operation_list)
+            } else {
+                None
+            }
+        }
+    }
+
+    if equation.operands.len() == 0 {
+        return solve_recursive_any_helper(equation.result, 0, &[]);
+    }
+
+    if let Some(mut solution) = solve_recursive_any_helper(
+        equation.result,
+        equation.operands[0],
+        &equation.operands[1..],
+    ) {
+        // the operands from the recursive any helper are returned in reverse order for efficiency (vector pushback is faster than vector pushfront)
+        solution.reverse();
+        Some(solution)
+    } else {
+        None
+    }
+}
+
+fn concat_values(lhs: usize, rhs: usize) -> usize {
+    // max u64 value = 9,223,372,036,854,775,807
+    let mut rhs_digit_counter = rhs;
+    let mut lhs_concat_scale = 10;
+    while rhs_digit_counter >= 10 {
+        rhs_digit_counter /= 10;
+        lhs_concat_scale *= 10;
+    }
+
+    (lhs * lhs_concat_scale) + rhs
+}
+
+fn solve_recursive_any_pt2(equation: &Equation) -> Option<Vec<Operation>> {
+    fn solve_recursive_any_helper(
+        exp_result: usize,
+        curr_value: usize,
+        operands_left: &[usize],
+    ) -> Option<Vec<Operation>> {
+        if operands_left.len() == 0 {
+            return if exp_result == curr_value {
+                Some(vec![])
+            } else {
+                None
+            };
+        }
+
+        let next_rhs_op: usize = operands_left[0];
+        let next_add_candidate = curr_value + next_rhs_op;
+        if let Some(mut solved_operation_list) =
+            solve_recursive_any_helper(exp_result, next_add_candidate, &operands_left[1..])
+        {
+            solved_operation_list.push(Operation::Add);
+            Some(solved_operation_list)
+        } else {
+            let next_mul_candidate = curr_value * next_rhs_op;
+            if let Some(mut solved_operation_list) =
+                solve_recursive_any_helper(exp_result, next_mul_candidate, &operands_left[1..])
+            {
+                solved_operation_list.push(Operation::Mul);
+                Some(solved_operation_list)
+            } else {
+                let next_concat_candidate = concat_values(curr_value, next_rhs_op);
+                if let Some(mut solved_operation_list) = solve_recursive_any_helper(
+                    exp_result,
+                    next_concat_candidate,
+                    &operands_left[1..],
+                ) {
+                    solved_operation_list.push(Operation::Concat);
+                    Some(solved_operation_list)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    if equation.operands.len() == 0 {
+        return solve_recursive_any_helper(equation.result, 0, &[]);
+    }
+
+    if let Some(mut solution) = solve_recursive_any_helper(
+        equation.result,
+        equation.operands[0],
+        &equation.operands[1..],
+    ) {
+        // the operands from the recursive any helper are returned in reverse order for efficiency (vector pushback is faster than vector pushfront)
+        solution.reverse();
+        Some(solution)
+    } else {
+        None
+    }
+}
+
+pub struct Day07;
+
+impl solution::Solution for Day07 {
+    type Input = Vec<Equation>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_equations(filename)
+    }
+
+    fn part1(equations: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let solved_equations_pt1: Vec<(Equation, Vec<Operation>)> = equations
+            .iter()
+            .map(|eq| (eq.clone(), solve_recursive_any_pt1(&eq)))
+            .filter(|(_eq, solution)| solution.is_some())
+            .map(|(eq, solution)| (eq, solution.unwrap()))
+            .collect();
+
+        // for (eq, _) in &solved_equations_pt1 {
+        //     println!("Found sol for {:?}", eq);
+        // }
+
+        let sum_solvable_results_pt1: usize =
+            solved_equations_pt1.iter().map(|(eq, _sol)| eq.result).sum();
+
+        Ok(sum_solvable_results_pt1)
+    }
+
+    fn part2(equations: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let solved_equations_pt2: Vec<(Equation, Vec<Operation>)> = equations
+            .iter()
+            .map(|eq| (eq.clone(), solve_recursive_any_pt2(&eq)))
+            .filter(|(_eq, solution)| solution.is_some())
+            .map(|(eq, solution)| (eq, solution.unwrap()))
+            .collect();
+
+        // for (eq, _) in &solved_equations_pt2 {
+        //     println!("Found sol for {:?}", eq);
+        // }
+
+        let sum_solvable_results_pt2: usize =
+            solved_equations_pt2.iter().map(|(eq, _sol)| eq.result).sum();
+
+        Ok(sum_solvable_results_pt2)
+    }
+}
@@ -0,0 +1,130 @@
+use input_helpers;
+use itertools::Itertools;
+use simple_grid::{Grid, GridPos};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tower {
+    freq: char
+}
+
+pub type TowerGrid = Grid<Option<Tower>>;
+
+fn read_tower_grid(filename: &str) -> Result<TowerGrid, String> {
+    let text = input_helpers::read_file_to_string(filename).map_err(|e| format!("{}: {}", filename, e))?;
+    let tower_grid = Grid::parse(&text, |c, _pos| match c {
+        '.' => Ok(None),
+        '0'..='9' | 'a'..='z' | 'A'..='Z' => Ok(Some(Tower{freq: c})),
+        _ => Err("'.' or a frequency tower ('0'-'9', 'a'-'z' or 'A'-'Z')"),
+    })
+    .map_err(|e| e.with_file(filename))?;
+
+    Ok(tower_grid)
+}
+
+fn dump_tower_grid(tower_grid: &TowerGrid) {
+    for r in 0..(tower_grid.height as isize) {
+        for c in 0..(tower_grid.width as isize) {
+            let print_char = match tower_grid.get_cell(r, c) {
+                Some(tower) => tower.freq,
+                None => '.',
+            };
+            print!("{}", print_char);
+        }
+        println!("");
+    }
+}
+
+fn find_tower_positions(tower_grid: &TowerGrid) -> std::collections::HashMap<char, Vec<GridPos>> {
+    let mut tower_positions = std::collections::HashMap::<char, Vec<GridPos>>::new();
+    for r in 0..(tower_grid.height as isize) {
+        for c in 0..(tower_grid.width as isize) {
+            if let Some(tower) = tower_grid.get_cell(r, c) {
+                tower_positions.entry(tower.freq).or_default().push(GridPos{row: r, col: c});
+            }
+        }
+    }
+    tower_positions
+}
+
+fn calculate_all_antinode_positions_pt1(tower_grid: &TowerGrid) -> std::collections::HashSet<GridPos> {
+    let tower_positions = find_tower_positions(tower_grid);
+
+    let mut antinode_positions = std::collections::HashSet::<GridPos>::new();
+
+    for (_freq, tower_positions) in &tower_positions {
+        // FIXME: drop all of the extra printlns in here
+        println!("freq({}): pos={:?}", _freq, tower_positions);
+        for (tower_a, tower_b) in tower_positions.iter().tuple_combinations() {
+            // an antinode is as far beyond each tower as the towers are apart, so it's the first
+            // cell of the ray that carries on from one tower past the other
+            let a_to_b = (tower_b.row - tower_a.row, tower_b.col - tower_a.col);
+            let b_to_a = (-a_to_b.0, -a_to_b.1);
+            for (tower, step) in [(tower_b, a_to_b), (tower_a, b_to_a)] {
+                if let Some((antinode_pos, _)) = tower_grid.ray_by(*tower, step).next() {
+                    antinode_positions.insert(antinode_pos);
+                }
+            }
+        }
+    }
+
+    antinode_positions
+}
+
+fn calculate_all_antinode_positions_pt2(tower_grid: &TowerGrid) -> std::collections::HashSet<GridPos> {
+    let tower_positions = find_tower_positions(tower_grid);
+
+    let mut antinode_positions = std::collections::HashSet::<GridPos>::new();
+
+    for (_freq, tower_positions) in &tower_positions {
+        // FIXME: drop all of the extra printlns in here
+        println!("freq({}): pos={:?}", _freq, tower_positions);
+        for (tower_a, tower_b) in tower_positions.iter().tuple_combinations() {
+            // every grid position in line with the two towers, out to the edges in both directions
+            antinode_positions.extend(tower_grid.line_through(*tower_a, *tower_b));
+        }
+    }
+
+    antinode_positions
+}
+
+pub struct Day08;
+
+impl solution::Solution for Day08 {
+    type Input = TowerGrid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_tower_grid(filename)
+    }
+
+    fn part1(tower_grid: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        dump_tower_grid(tower_grid);
+
+        let antinode_positions_pt1 = calculate_all_antinode_positions_pt1(tower_grid);
+        let antinode_position_count = antinode_positions_pt1.len();
+        if antinode_positions_pt1.len() < 10 {
+            for p in antinode_positions_pt1 {
+                println!("- (r:{},c:{})", p.row, p.col);
+            }
+        }
+
+        Ok(antinode_position_count)
+    }
+
+    fn part2(tower_grid: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let antinode_positions_pt2 = calculate_all_antinode_positions_pt2(tower_grid);
+        let antinode_position_count = antinode_positions_pt2.len();
+        if antinode_positions_pt2.len() < 10 {
+            for p in antinode_positions_pt2 {
+                println!("- (r:{},c:{})", p.row, p.col);
+            }
+        }
+
+        Ok(antinode_position_count)
+    }
+}
@@ -0,0 +1,167 @@
+use input_helpers;
+use itertools::Itertools;
+use simple_grid::{Grid, GridPos};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct HeightIndex {
+    val: u8,
+}
+
+impl HeightIndex {
+    fn can_climb_to(&self, other: &Self) -> bool {
+        self.val + 1 == other.val
+    }
+
+    fn is_trailhead(&self) -> bool {
+        self.val == 0
+    }
+
+    fn is_trailend(&self) -> bool {
+        self.val == 9
+    }
+}
+
+fn dump_trail_map(trail_map: &TopographicTrailMap) {
+    for r in 0..(trail_map.height as isize) {
+        for c in 0..(trail_map.width as isize) {
+            let height_index_char = (trail_map.get_cell(r, c).val + ('0' as u8)) as char;
+            print!("{}", height_index_char);
+        }
+        println!("");
+    }
+}
+
+pub type TopographicTrailMap = Grid<HeightIndex>;
+
+fn read_topographic_trail_map(filename: &str) -> Result<TopographicTrailMap, String> {
+    let text = input_helpers::read_file_to_string(filename).map_err(|e| format!("{}: {}", filename, e))?;
+
+    let trail_map = Grid::parse(&text, |c, _pos| match c {
+        '0'..='9' => {
+            let u8_height_val: u8 = (c as u8) - ('0' as u8);
+            Ok(HeightIndex { val: u8_height_val })
+        }
+        _ => Err("a height from 0 to 9"),
+    })
+    .map_err(|e| e.with_file(filename))?;
+
+    Ok(trail_map)
+}
+
+fn find_trails_pt1(trail_map: &TopographicTrailMap, start_pos: &GridPos) -> Vec<GridPos> {
+    trail_map
+        .flood_fill(*start_pos, |from, to| from.can_climb_to(&to))
+        .into_iter()
+        .filter(|pos| trail_map.get_cell(pos.row, pos.col).is_trailend())
+        .collect()
+}
+
+fn find_all_trails_pt1(
+    trail_map: &TopographicTrailMap,
+) -> std::collections::HashMap<GridPos, Vec<GridPos>> {
+    let mut trails = std::collections::HashMap::<GridPos, Vec<GridPos>>::new();
+    for r in 0..(trail_map.height as isize) {
+        for c in 0..(trail_map.width as isize) {
+            if trail_map.get_cell(r, c).is_trailhead() {
+                let trailhead_pos = GridPos { row: r, col: c };
+                trails.insert(trailhead_pos, find_trails_pt1(trail_map, &trailhead_pos));
+            }
+        }
+    }
+
+    trails
+}
+
+fn find_trails_pt2(trail_map: &TopographicTrailMap, start_pos: &GridPos) -> Vec<GridPos> {
+    // every distinct trail counts here, so unlike pt1 the same trailend can be reached repeatedly
+    let mut trailends = Vec::<GridPos>::new();
+
+    fn find_trails_rec_helper(
+        trail_map: &TopographicTrailMap,
+        curr_pos: &GridPos,
+        trailends: &mut Vec<GridPos>,
+    ) {
+        let curr_trail_cell = trail_map.get_cell(curr_pos.row, curr_pos.col);
+        if curr_trail_cell.is_trailend() {
+            trailends.push(curr_pos.clone());
+            return;
+        }
+
+        for neighbor_pos in trail_map.neighbors4(*curr_pos) {
+            let neighbor = trail_map.get_cell(neighbor_pos.row, neighbor_pos.col);
+            if curr_trail_cell.can_climb_to(&neighbor) {
+                find_trails_rec_helper(trail_map, &neighbor_pos, trailends);
+            }
+        }
+    }
+
+    find_trails_rec_helper(trail_map, start_pos, &mut trailends);
+
+    trailends
+}
+
+fn find_all_trails_pt2(
+    trail_map: &TopographicTrailMap,
+) -> std::collections::HashMap<GridPos, Vec<GridPos>> {
+    let mut trails = std::collections::HashMap::<GridPos, Vec<GridPos>>::new();
+    for r in 0..(trail_map.height as isize) {
+        for c in 0..(trail_map.width as isize) {
+            if trail_map.get_cell(r, c).is_trailhead() {
+                let trailhead_pos = GridPos { row: r, col: c };
+                trails.insert(trailhead_pos, find_trails_pt2(trail_map, &trailhead_pos));
+            }
+        }
+    }
+
+    trails
+}
+
+pub struct Day10;
+
+impl solution::Solution for Day10 {
+    type Input = TopographicTrailMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_topographic_trail_map(filename)
+    }
+
+    fn part1(trail_map: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        dump_trail_map(trail_map);
+
+        let trails = find_all_trails_pt1(trail_map);
+        let trailhead_scores: Vec<usize> = trails
+            .iter()
+            .map(|(_trail_start, trail_ends)| trail_ends.len())
+            .collect();
+        let trailhead_score_sum: usize = trailhead_scores.iter().sum();
+        if trails.len() < 20 {
+            for trail in &trails {
+                println!("- start={}; trail={:?}", trail.0, trail.1);
+            }
+        }
+
+        Ok(trailhead_score_sum)
+    }
+
+    fn part2(trail_map: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let trails = find_all_trails_pt2(trail_map);
+        let trailhead_ratings: Vec<usize> = trails
+            .iter()
+            .map(|(_trail_start, trail_ends)| trail_ends.len())
+            .collect();
+        let trailhead_rating_sum: usize = trailhead_ratings.iter().sum();
+        if trails.len() < 20 {
+            for trail in &trails {
+                println!("- start={}; trail={:?}", trail.0, trail.1);
+            }
+        }
+
+        Ok(trailhead_rating_sum)
+    }
+}
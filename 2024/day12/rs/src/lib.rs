@@ -0,0 +1,103 @@
+use input_helpers;
+use simple_grid::{Grid, Region};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct GardenPlot {
+    plant_type: char,
+}
+
+struct GardenRegion {
+    plant_type: char,
+    plots: Region,
+}
+
+fn dump_garden(garden: &Grid<GardenPlot>) {
+    for r in 0..(garden.height as isize) {
+        for c in 0..(garden.width as isize) {
+            print!("{}", garden.get_cell(r, c).plant_type);
+        }
+        println!("");
+    }
+}
+
+fn split_regions(garden: &Grid<GardenPlot>) -> Vec<GardenRegion> {
+    garden
+        .components(|a, b| a.plant_type == b.plant_type)
+        .cells
+        .into_iter()
+        .map(|plot_positions| GardenRegion {
+            plant_type: garden.get_cell(plot_positions[0].row, plot_positions[0].col).plant_type,
+            plots: plot_positions.into_iter().collect(),
+        })
+        .collect()
+}
+
+fn read_garden_map(filename: &str) -> Result<Grid<GardenPlot>, String> {
+    let text = input_helpers::read_file_to_string(filename).map_err(|e| format!("{}: {}", filename, e))?;
+
+    let garden = Grid::parse(&text, |c, _pos| match c {
+        'A'..='Z' => Ok(GardenPlot { plant_type: c }),
+        _ => Err("a plant type from 'A' to 'Z'"),
+    })
+    .map_err(|e| e.with_file(filename))?;
+
+    Ok(garden)
+}
+
+pub struct Day12;
+
+impl solution::Solution for Day12 {
+    type Input = Grid<GardenPlot>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_garden_map(filename)
+    }
+
+    fn part1(garden: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        dump_garden(garden);
+
+        let regions = split_regions(garden);
+        let mut total_fence_price = 0;
+        let print_region_info = regions.len() < 20;
+        for (i, region) in regions.iter().enumerate() {
+            let area = region.plots.area();
+            let perimeter = region.plots.perimeter();
+            let price = area * perimeter;
+            total_fence_price += price;
+            if print_region_info {
+                println!(
+                    " {:02}. {} ${} = {}(area) x {}(peri)  ::  {:?}",
+                    i, region.plant_type, price, area, perimeter, region.plots.cells()
+                );
+            }
+        }
+
+        Ok(total_fence_price)
+    }
+
+    fn part2(garden: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        input_helpers::Options::new().parse(args)?;
+
+        let regions = split_regions(garden);
+        let mut total_fence_price = 0;
+        let print_region_info = regions.len() < 20;
+        for (i, region) in regions.iter().enumerate() {
+            let area = region.plots.area();
+            let side_count = region.plots.side_count();
+            let price = area * side_count;
+            total_fence_price += price;
+            if print_region_info {
+                println!(
+                    " {:02}. {} ${} = {}(area) x {}(sides)  ::  {:?}",
+                    i, region.plant_type, price, area, side_count, region.plots.cells()
+                );
+            }
+        }
+
+        Ok(total_fence_price)
+    }
+}
@@ -0,0 +1,211 @@
+use input_helpers::ParseError;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Vec2 {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ClawMachine {
+    button_a_move: Vec2,
+    button_b_move: Vec2,
+    prize_pos: Vec2,    
+}
+
+impl std::fmt::Display for ClawMachine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ClawMachine{{A(+{},+{}); B(+{},{}); Prize({},{})}}",
+            self.button_a_move.x, 
+            self.button_a_move.y, 
+            self.button_b_move.x, 
+            self.button_b_move.y, 
+            self.prize_pos.x, 
+            self.prize_pos.y)
+    }
+}
+
+impl std::fmt::Display for ClawMachineSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ClawMachineSolution{{A: {}, B: {}}}",
+            self.a_press_count,
+            self.b_press_count)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct ClawMachineSolution {
+    a_press_count: usize,
+    b_press_count: usize,
+}
+
+fn read_claw_machine_summaries(filename: &str) -> Result<Vec<ClawMachine>, String> {
+    let lines: Vec<String> = input_helpers::try_read_all_lines(filename)?;
+    parse_claw_machine_summaries(&lines).map_err(|e| e.with_file(filename).into())
+}
+
+fn parse_claw_machine_summaries(lines: &[String]) -> Result<Vec<ClawMachine>, ParseError> {
+    fn scan_vec2(line_number: usize, line: &str, pattern: &str) -> Result<Vec2, ParseError> {
+        let (x, y) = input_helpers::scan!(line_number, line, pattern, usize, usize)?;
+        Ok(Vec2 {x, y})
+    }
+
+    let read_claw_machine_summary = |section: input_helpers::Section| -> Result<ClawMachine, ParseError> {
+        if section.lines.len() != 3 {
+            // Point at the first extra line, or the last line if some are missing
+            let bad_line_idx = if section.lines.len() > 3 { 3 } else { section.lines.len() - 1 };
+            return Err(ParseError::whole_line(section.first_line_number + bad_line_idx, &section.lines[bad_line_idx],
+                "3 lines per claw machine description with 1 empty line in between each machine"));
+        }
+
+        let line_number = section.first_line_number;
+        let button_a_vec = scan_vec2(line_number, &section.lines[0], "Button A: X+{}, Y+{}")?;
+        let button_b_vec = scan_vec2(line_number + 1, &section.lines[1], "Button B: X+{}, Y+{}")?;
+        let prize_vec = scan_vec2(line_number + 2, &section.lines[2], "Prize: X={}, Y={}")?;
+
+        Ok(ClawMachine{button_a_move: button_a_vec, button_b_move: button_b_vec, prize_pos: prize_vec})
+    };
+
+    input_helpers::sections(lines).map(read_claw_machine_summary).collect()
+}
+
+fn calculate_claw_position(claw_machine: &ClawMachine, a_press_cnt: usize, b_press_cnt: usize) -> Vec2 {
+    Vec2 {
+        x: (claw_machine.button_a_move.x * a_press_cnt) + (claw_machine.button_b_move.x * b_press_cnt),
+        y: (claw_machine.button_a_move.y * a_press_cnt) + (claw_machine.button_b_move.y * b_press_cnt),
+    }
+}
+
+fn find_all_solutions(claw_machine: &ClawMachine) -> Vec<ClawMachineSolution> {
+    let mut solutions = vec![];
+
+    let max_a_presses_to_x = claw_machine.prize_pos.x / claw_machine.button_a_move.x;
+    let max_a_presses_to_y = claw_machine.prize_pos.y / claw_machine.button_a_move.y;
+    let max_a_presses = std::cmp::min(max_a_presses_to_x, max_a_presses_to_y);
+
+    let max_b_presses_to_x = claw_machine.prize_pos.x / claw_machine.button_b_move.x;
+    let max_b_presses_to_y = claw_machine.prize_pos.y / claw_machine.button_b_move.y;
+    let max_b_presses = std::cmp::min(max_b_presses_to_x, max_b_presses_to_y);
+
+    for a_press_cnt in 0..max_a_presses+1 {
+        for b_press_cnt in 0..max_b_presses+1 {
+            let claw_position = calculate_claw_position(claw_machine, a_press_cnt, b_press_cnt);
+
+            // Found solution
+            if claw_position == claw_machine.prize_pos {
+                solutions.push(ClawMachineSolution{a_press_count: a_press_cnt, b_press_count: b_press_cnt});
+                break;
+            }
+
+            // No solution
+            if claw_position.x > claw_machine.prize_pos.x || claw_position.y > claw_machine.prize_pos.y {
+                break;
+            }
+        }
+    }
+
+    solutions
+}
+
+fn count_tokens_for_solution(solution: &ClawMachineSolution) -> usize {
+    (solution.a_press_count * 3) + solution.b_press_count
+}
+
+fn options() -> input_helpers::Options {
+    input_helpers::Options::new()
+        .value("offset", 10_000_000_000_000usize, "how far pt 2 moves each prize")
+}
+
+pub struct Day13;
+
+impl solution::Solution for Day13 {
+    type Input = Vec<ClawMachine>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_claw_machine_summaries(filename)
+    }
+
+    fn part1(claw_machines: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        options().parse(args)?;
+
+        let print_machines = claw_machines.len() < 10;
+        let mut total_min_tokens: Option<usize> = None;
+        for claw_machine in claw_machines {
+            let solutions = find_all_solutions(claw_machine);
+            let min_cost_solution = solutions
+                .iter()
+                .enumerate()
+                .map(|(i, solution)| (i, count_tokens_for_solution(solution)))
+                .min_by_key(|(_i, solution_token_count)| solution_token_count.clone());
+            if let Some((solution_idx, min_cost_solution_token_count)) = min_cost_solution {
+                if let Some(token_count) = total_min_tokens {
+                    total_min_tokens = Some(token_count + min_cost_solution_token_count);
+                } else {
+                    total_min_tokens = Some(min_cost_solution_token_count);
+                }
+
+                if print_machines {
+                    println!("{}", claw_machine);
+                    println!("{}", solutions[solution_idx]);
+                    println!("");
+                }
+            } else {
+                if print_machines {
+                    println!("{}", claw_machine);
+                    println!("NO SOLUTION");
+                    println!("");
+                }
+            }
+        }
+
+        total_min_tokens.ok_or("No claw machines have solutions".into())
+    }
+
+    // FIXME: pt 2 still needs a solver which doesn't brute force every button press combination.
+    fn part2(_claw_machines: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let _claw_machine_offset: usize = options().parse(args)?.get("offset")?;
+
+        /*
+        {
+            let print_machines = claw_machines.len() < 10;
+            let mut total_min_tokens: Option<usize> = None;
+            for claw_machine in &claw_machines {
+                let solutions = find_all_solutions_with_offset(claw_machine, claw_machine_offset);
+                let min_cost_solution = solutions
+                    .iter()
+                    .enumerate()
+                    .map(|(i, solution)| (i, count_tokens_for_solution(solution)))
+                    .min_by_key(|(_i, solution_token_count)| solution_token_count.clone());
+                if let Some((solution_idx, min_cost_solution_token_count)) = min_cost_solution {
+                    if let Some(token_count) = total_min_tokens {
+                        total_min_tokens = Some(token_count + min_cost_solution_token_count);
+                    } else {
+                        total_min_tokens = Some(min_cost_solution_token_count);
+                    }
+
+                    if print_machines {
+                        println!("{}", claw_machine);
+                        println!("{}", solutions[solution_idx]);
+                        println!("");
+                    }
+                } else {
+                    if print_machines {
+                        println!("{}", claw_machine);
+                        println!("NO SOLUTION");
+                        println!("");
+                    }
+                }
+            }
+
+            if let Some(total_min_tokens) = total_min_tokens {
+                println!("Pt 2: min token count = {}", total_min_tokens);
+            } else {
+                println!("Pt 2: min token count = NO SOLUTIONS");
+            }
+        } */
+
+        Err("Pt 2 is not implemented".into())
+    }
+}
@@ -0,0 +1,292 @@
+use input_helpers::ParseError;
+use simple_grid::{GridPos, Wrap, WrappingBounds};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Vec2 {
+    x: isize,
+    y: isize,
+}
+
+impl std::fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(x:{},y:{})", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Robot {
+    pos: Vec2,
+    vel: Vec2,
+}
+
+pub struct RobotArea {
+    width: usize,
+    height: usize,
+    bounds: WrappingBounds,
+}
+
+/// Moves every robot `step_count` steps at once, teleporting across the edges as it goes.
+fn advance_robots(robots: &mut [Robot], robot_area: &RobotArea, step_count: usize) {
+    let bounds = robot_area.bounds;
+    for robot in robots.iter_mut() {
+        let pos = bounds.advance(
+            GridPos {
+                row: robot.pos.y,
+                col: robot.pos.x,
+            },
+            (robot.vel.y, robot.vel.x),
+            step_count,
+        );
+        robot.pos = Vec2 {
+            x: pos.col,
+            y: pos.row,
+        };
+    }
+}
+
+fn dump_grid_to_str(title: &str, robot_area: &RobotArea, robots: &[Robot]) -> String {
+    let mut string_buf = String::with_capacity(robot_area.width);
+
+    string_buf.push_str(&format!("{}:\n", title));
+    for r in 0..robot_area.height as isize {
+        for c in 0..robot_area.width as isize {
+            let pos = Vec2 { x: c, y: r };
+            let robots_in_pos = robots.iter().filter(|r| r.pos == pos).count();
+            let cell_char = match robots_in_pos {
+                0 => '.',
+                1..=9 => (('0' as usize) + robots_in_pos) as u8 as char,
+                10..=35 => (('A' as usize) + (robots_in_pos - 10)) as u8 as char,
+                36..=61 => (('a' as usize) + (robots_in_pos - 36)) as u8 as char,
+                _ => panic!("not enough chars to represent {}", robots_in_pos),
+            };
+            string_buf.push(cell_char);
+            string_buf.push(' ');
+        }
+        string_buf.push('\n');
+    }
+    string_buf
+}
+
+fn dump_grid(title: &str, robot_area: &RobotArea, robots: &[Robot]) {
+    print!("{}", dump_grid_to_str(title, robot_area, robots));
+}
+
+fn read_robots(filename: &str) -> Result<(RobotArea, Vec<Robot>), String> {
+    let lines: Vec<String> = input_helpers::try_read_all_lines(filename)?;
+    parse_robots(&lines).map_err(|e| e.with_file(filename).into())
+}
+
+fn parse_robots(lines: &[String]) -> Result<(RobotArea, Vec<Robot>), ParseError> {
+    if lines.is_empty() {
+        return Err(ParseError::whole_line(
+            1,
+            "",
+            "the width and height of the robot area",
+        ));
+    }
+
+    let (width, height) = input_helpers::scan!(1, &lines[0], "{} {}", usize, usize)?;
+    let bounds = WrappingBounds::new(width, height, Wrap::Both).ok_or_else(|| {
+        ParseError::whole_line(1, &lines[0], "a width and height of at least 1")
+    })?;
+    let robot_area = RobotArea { width, height, bounds };
+
+    let mut robots = vec![];
+    for (line_idx, line) in lines.iter().enumerate().skip(1) {
+        let (px, py, vx, vy) = input_helpers::scan!(
+            line_idx + 1,
+            line,
+            "p={},{} v={},{}",
+            isize,
+            isize,
+            isize,
+            isize
+        )?;
+
+        let robot = Robot {
+            pos: Vec2 { x: px, y: py },
+            vel: Vec2 { x: vx, y: vy },
+        };
+        robots.push(robot);
+    }
+
+    Ok((robot_area, robots))
+}
+
+fn step_by_step_simulation(
+    robots: &mut [Robot],
+    robot_area: &RobotArea,
+    simulation_step_count: usize,
+    print_grid: bool,
+    in_place_print: bool,
+) {
+    let cursor_move = format!("\x1b[{}A", robot_area.height + 1);
+
+    for i in 0..simulation_step_count {
+        advance_robots(robots, robot_area, 1);
+
+        if print_grid {
+            let grid_str =
+                dump_grid_to_str(&format!("after step {:03}", i + 1), robot_area, &robots);
+            print!("{}", grid_str);
+        }
+
+        if in_place_print && (i < simulation_step_count - 1) {
+            std::thread::sleep(std::time::Duration::from_millis(250));
+            print!("{}", cursor_move);
+        }
+    }
+}
+
+fn count_robots_in_quadrants(
+    robots: &[Robot],
+    robot_area: &RobotArea,
+) -> (usize, usize, usize, usize) {
+    /*
+    |----|-----
+    | Q1 | Q2 |
+    |----|-----
+    | Q3 | Q4 |
+    |----|-----
+    */
+
+    struct QuadrantBounds {
+        topleft: Vec2,
+        bottomright: Vec2,
+    }
+
+    fn in_quadrant_bounds(bounds: &QuadrantBounds, pos: &Vec2) -> bool {
+        pos.x >= bounds.topleft.x
+            && pos.x <= bounds.bottomright.x
+            && pos.y >= bounds.topleft.y
+            && pos.y <= bounds.bottomright.y
+    }
+
+    // FIXME:  assume width and height are odd values for now. Its the sizes provided in both the sample problem
+    // and the real pt1 input. Assuming odd makes it easier to calculate area bounds or now
+    assert!(robot_area.height % 2 == 1);
+    assert!(robot_area.width % 2 == 1);
+    let mid_x = (robot_area.width / 2) as isize;
+    let mid_y = (robot_area.height / 2) as isize;
+    let top_x = (robot_area.width - 1) as isize;
+    let top_y = (robot_area.height - 1) as isize;
+
+    let q1_bounds = QuadrantBounds {
+        topleft: Vec2 { x: 0, y: 0 },
+        bottomright: Vec2 {
+            x: mid_x - 1,
+            y: mid_y - 1,
+        },
+    };
+    let q2_bounds = QuadrantBounds {
+        topleft: Vec2 { x: mid_x + 1, y: 0 },
+        bottomright: Vec2 {
+            x: top_x,
+            y: mid_y - 1,
+        },
+    };
+    let q3_bounds = QuadrantBounds {
+        topleft: Vec2 { x: 0, y: mid_y + 1 },
+        bottomright: Vec2 {
+            x: mid_x - 1,
+            y: top_y,
+        },
+    };
+    let q4_bounds = QuadrantBounds {
+        topleft: Vec2 {
+            x: mid_x + 1,
+            y: mid_y + 1,
+        },
+        bottomright: Vec2 { x: top_x, y: top_y },
+    };
+
+    let mut quadrant_counts = (0, 0, 0, 0);
+
+    for robot in robots {
+        if in_quadrant_bounds(&q1_bounds, &robot.pos) {
+            quadrant_counts.0 += 1;
+        } else if in_quadrant_bounds(&q2_bounds, &robot.pos) {
+            quadrant_counts.1 += 1;
+        } else if in_quadrant_bounds(&q3_bounds, &robot.pos) {
+            quadrant_counts.2 += 1;
+        } else if in_quadrant_bounds(&q4_bounds, &robot.pos) {
+            quadrant_counts.3 += 1;
+        }
+    }
+
+    quadrant_counts
+}
+
+fn simulate_and_print_safety_factor(
+    title: &str,
+    robot_area: &RobotArea,
+    robots: &[Robot],
+    simulation_step_count: usize,
+    print_each_step: bool,
+) -> usize {
+    if robot_area.width * robot_area.height < 250 {
+        dump_grid(&format!("{} start state", title), robot_area, robots);
+    }
+
+    let mut simulated_robots = robots.to_vec();
+    if print_each_step {
+        step_by_step_simulation(
+            &mut simulated_robots,
+            robot_area,
+            simulation_step_count,
+            print_each_step,
+            print_each_step,
+        );
+    } else {
+        advance_robots(&mut simulated_robots, robot_area, simulation_step_count);
+    }
+    let quadrant_counts = count_robots_in_quadrants(&simulated_robots, robot_area);
+
+    if robot_area.width * robot_area.height < 250 {
+        dump_grid(&format!("{} end state", title), robot_area, &simulated_robots);
+    }
+
+    let total_safety_factor =
+        quadrant_counts.0 * quadrant_counts.1 * quadrant_counts.2 * quadrant_counts.3;
+    println!(
+        "{} Total safety factor: {} = {} * {} * {} * {}",
+        title,
+        total_safety_factor,
+        quadrant_counts.0,
+        quadrant_counts.1,
+        quadrant_counts.2,
+        quadrant_counts.3
+    );
+
+    total_safety_factor
+}
+
+fn options() -> input_helpers::Options {
+    input_helpers::Options::new().value("steps", 10000, "how many steps pt 2 prints")
+}
+
+pub struct Day14;
+
+impl solution::Solution for Day14 {
+    type Input = (RobotArea, Vec<Robot>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_robots(filename)
+    }
+
+    fn part1((robot_area, robots): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        options().parse(args)?;
+
+        Ok(simulate_and_print_safety_factor("Pt 1.", robot_area, robots, 100, false))
+    }
+
+    // Pt 2 is still unsolved. Step through the simulation and print each step so the christmas tree can be found by eye.
+    fn part2((robot_area, robots): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let simulation_step_count: usize = options().parse(args)?.get("steps")?;
+
+        simulate_and_print_safety_factor("Pt 2.", robot_area, robots, simulation_step_count, true);
+        Err("Pt 2 has no solver; look for the christmas tree in the printed steps".into())
+    }
+}
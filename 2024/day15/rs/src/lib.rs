@@ -0,0 +1,218 @@
+use input_helpers;
+use simple_grid::{Grid, GridPos};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Space {
+    Empty,
+    Box,
+    Wall,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Move {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+pub type Warehouse = Grid<Space>;
+
+fn read_input(filename: &str) -> Result<(Grid<Space>, GridPos, Vec<Move>), String> {
+    let lines: Vec<String> = input_helpers::try_read_all_lines(filename)?;
+
+    let (grid_section, move_section) =
+        input_helpers::two_sections(&lines, "the warehouse map", "the robot's moves")
+            .map_err(|e| e.with_file(filename))?;
+    let grid_lines = grid_section.lines;
+    let move_lines = move_section.lines;
+
+    fn is_full_wall_line(line: &str) -> bool {
+        !line.is_empty() && line.chars().all(|c| c == '#')
+    }
+
+    let first_grid_line = &grid_lines[0];
+    let last_grid_line = &grid_lines[grid_lines.len() - 1];
+    if grid_lines.len() < 2 || !is_full_wall_line(first_grid_line) || !is_full_wall_line(last_grid_line) {
+        return Err(String::from(
+            "Invalid input! The warehouse map must start and end with a line of all '#'s",
+        ));
+    }
+
+    let (warehouse_grid, markers) = Grid::parse_with_markers(grid_section, &['@'], |c, _pos| match c {
+        '.' | '@' => Ok(Space::Empty),
+        'O' => Ok(Space::Box),
+        '#' => Ok(Space::Wall),
+        _ => Err("'.', 'O', '#' or the robot ('@')"),
+    })
+    .map_err(|e| e.with_file(filename))?;
+    let robot_pos = markers.only('@').map_err(|e| format!("{}: {}", filename, e))?;
+
+    let mut moves = vec![];
+    for line in move_lines {
+        for c in line.chars() {
+            let move_instr = match c {
+                '<' => Move::Left,
+                '^' => Move::Up,
+                '>' => Move::Right,
+                'v' => Move::Down,
+                _ => return Err(format!("Invalid move char! {}", c)),
+            };
+
+            moves.push(move_instr);
+        }
+    }
+
+    Ok((warehouse_grid, robot_pos, moves))
+}
+
+fn dump_warehouse(warehouse: &Warehouse, robot_pos: &GridPos) -> String {
+    let mut buf = String::with_capacity((warehouse.width + 1) * warehouse.height);
+    for r in 0..(warehouse.height as isize) {
+        for c in 0..(warehouse.width as isize) {
+            let cell_pos = GridPos { row: r, col: c };
+            let cell_char = if cell_pos == *robot_pos {
+                '@'
+            } else {
+                match warehouse.get_cell(r, c) {
+                    Space::Empty => '.',
+                    Space::Box => 'O',
+                    Space::Wall => '#',
+                }
+            };
+            buf.push(cell_char);
+        }
+        buf.push('\n');
+    }
+    buf
+}
+
+fn print_warehouse(title: Option<&str>, warehouse: &Warehouse, robot_pos: &GridPos) {
+    if let Some(title) = title {
+        println!("{}: ", title);
+    }
+    println!("{}", dump_warehouse(warehouse, robot_pos));
+}
+
+fn do_move(warehouse: &mut Warehouse, robot_pos: &mut GridPos, move_instr: Move) {
+    let (row_offset, col_offset) = match move_instr {
+        Move::Up => (-1, 0),
+        Move::Left => (0, -1),
+        Move::Down => (1, 0),
+        Move::Right => (0, 1),
+    };
+
+    let next_cell_pos = GridPos {
+        row: robot_pos.row + row_offset,
+        col: robot_pos.col + col_offset,
+    };
+    if warehouse.is_pos_out_of_bounds(next_cell_pos.row, next_cell_pos.col) {
+        return;
+    }
+
+    fn recursive_move_boxes(
+        row_offset: isize,
+        col_offset: isize,
+        warehouse: &mut Warehouse,
+        box_pos: &GridPos,
+    ) -> bool {
+        let next_cell_pos = GridPos {
+            row: box_pos.row + row_offset,
+            col: box_pos.col + col_offset,
+        };
+        if warehouse.is_pos_out_of_bounds(next_cell_pos.row, next_cell_pos.col) {
+            return false;
+        }
+
+        match warehouse.get_cell(next_cell_pos.row, next_cell_pos.col) {
+            Space::Wall => false,
+            Space::Box => {
+                if recursive_move_boxes(row_offset, col_offset, warehouse, &next_cell_pos) {
+                    *warehouse.get_cell_mut(next_cell_pos.row, next_cell_pos.col) = Space::Box;
+                    *warehouse.get_cell_mut(box_pos.row, box_pos.col) = Space::Empty;
+                    true
+                } else {
+                    false
+                }
+            }
+            Space::Empty => {
+                *warehouse.get_cell_mut(next_cell_pos.row, next_cell_pos.col) = Space::Box;
+                *warehouse.get_cell_mut(box_pos.row, box_pos.col) = Space::Empty;
+                true
+            }
+        }
+    }
+
+    match warehouse.get_cell(next_cell_pos.row, next_cell_pos.col) {
+        Space::Wall => (),                          // no move for wall
+        Space::Empty => *robot_pos = next_cell_pos, // move into the empty space
+        Space::Box => {
+            if recursive_move_boxes(row_offset, col_offset, warehouse, &next_cell_pos) {
+                *robot_pos = next_cell_pos;
+            }
+        }
+    }
+}
+
+fn calc_box_gps(box_pos: &GridPos) -> usize {
+    ((100 * box_pos.row) + box_pos.col) as usize
+}
+
+fn options() -> input_helpers::Options {
+    input_helpers::Options::new().flag("verbose", Some('v'), "print the warehouse after each move")
+}
+
+pub struct Day15;
+
+impl solution::Solution for Day15 {
+    type Input = (Warehouse, GridPos, Vec<Move>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_input(filename)
+    }
+
+    fn part1((warehouse, robot_pos, moves): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let verbose = options().parse(args)?.flag("verbose");
+
+        let mut warehouse = warehouse.clone();
+        let mut robot_pos = *robot_pos;
+
+        if verbose {
+            print_warehouse(Some("warehouse start"), &warehouse, &robot_pos);
+        }
+
+        for (i, move_instr) in moves.iter().enumerate() {
+            do_move(&mut warehouse, &mut robot_pos, *move_instr);
+            if verbose {
+                if i < moves.len() {
+                    print_warehouse(
+                        Some(&format!("after move {:03}", i)),
+                        &warehouse, &robot_pos,
+                    );
+                } else {
+                    print_warehouse(Some("warehouse end"), &warehouse, &robot_pos);
+                }
+            }
+        }
+
+        let mut sum_gps_coords = 0;
+        for r in 0..warehouse.height as isize {
+            for c in 0..warehouse.width as isize {
+                if let Space::Box = warehouse.get_cell(r, c) {
+                    let box_pos = GridPos { row: r, col: c };
+                    let box_gps = calc_box_gps(&box_pos);
+                    sum_gps_coords += box_gps;
+                }
+            }
+        }
+
+        Ok(sum_gps_coords)
+    }
+
+    fn part2(_input: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        options().parse(args)?;
+        Err("Pt 2 is not implemented".into())
+    }
+}
@@ -0,0 +1,244 @@
+use input_helpers;
+use simple_grid::{path, Dir4, Grid, GridPos};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+enum Space {
+    Empty,
+    Wall,
+}
+
+pub struct StartingState {
+    maze: Grid<Space>,
+    start_pos: GridPos,
+    end_pos: GridPos,
+    starting_dir: Dir4,
+}
+
+fn read_input(filename: &str) -> Result<StartingState, String> {
+    let text = input_helpers::read_file_to_string(filename).map_err(|e| format!("{}: {}", filename, e))?;
+
+    let (maze, markers) = Grid::parse_with_markers(&text, &['S', 'E'], |c, _pos| match c {
+        '.' | 'S' | 'E' => Ok(Space::Empty),
+        '#' => Ok(Space::Wall),
+        _ => Err("'.', '#', the start ('S') or the end ('E')"),
+    })
+    .map_err(|e| e.with_file(filename))?;
+
+    let only_marker = |marker| markers.only(marker).map_err(|e| format!("{}: {}", filename, e));
+    Ok(StartingState {
+        maze,
+        start_pos: only_marker('S')?,
+        end_pos: only_marker('E')?,
+        starting_dir: Dir4::E,
+    })
+}
+
+fn dump_dist_grid(maze: &Grid<Space>, min_dist_grid: &Grid<Option<usize>>) -> String {
+    fn count_digits(n: usize) -> usize {
+        let mut n = n;
+        let mut digit_count = 1;
+        while n > 9 {
+            n /= 10;
+            digit_count += 1;
+        }
+        digit_count
+    }
+
+    let max_digit_count = min_dist_grid
+        .cells
+        .iter()
+        .map(|dist| {
+            if let Some(dist) = dist {
+                count_digits(*dist)
+            } else {
+                3 // "Inf"
+            }
+        })
+        .max()
+        .unwrap_or(0);
+
+    let wall_cell_str = {
+        let mut buf = String::new();
+        buf.push('[');
+        for _ in 0..max_digit_count {
+            buf.push('#');
+        }
+        buf.push(']');
+        buf
+    };
+
+    let inf_cell_str = {
+        assert!(max_digit_count >= "INF".len());
+        let mut buf = String::new();
+        buf.push('[');
+        for _ in 0..(max_digit_count / 2 - 1) {
+            buf.push(' ');
+        }
+        buf.push('I');
+        buf.push('N');
+        buf.push('F');
+        let remaining_digit_spaces = max_digit_count - (max_digit_count / 2 + 3 - 1);
+        for _ in 0..remaining_digit_spaces {
+            buf.push(' ');
+        }
+        buf.push(']');
+        buf
+    };
+
+    fn fmt_num_cell(n: usize, max_digit_count: usize) -> String {
+        let digit_count = count_digits(n);
+        assert!(max_digit_count >= digit_count);
+        let mut buf = String::new();
+        buf.push('[');
+        for _ in 0..(max_digit_count / 2 - digit_count / 2) {
+            buf.push(' ');
+        }
+        buf.push_str(&n.to_string());
+        let remaining_digit_spaces =
+            max_digit_count - (max_digit_count / 2 - digit_count / 2) - (digit_count);
+        for _ in 0..remaining_digit_spaces {
+            buf.push(' ');
+        }
+        buf.push(']');
+        buf
+    }
+
+    let mut buf = String::with_capacity((maze.width + 1) * maze.height);
+    for r in 0..(maze.height as isize) {
+        for c in 0..(maze.width as isize) {
+            let cell_str = if let Space::Wall = maze.get_cell(r, c) {
+                wall_cell_str.clone()
+            } else {
+                if let Some(dist) = min_dist_grid.get_cell(r, c) {
+                    fmt_num_cell(dist, max_digit_count)
+                } else {
+                    inf_cell_str.clone()
+                }
+            };
+            buf.push_str(&cell_str);
+        }
+        buf.push('\n');
+    }
+    buf
+}
+
+/// A reindeer's place in the maze: where it stands and which way it faces.
+type ReindeerState = (GridPos, Dir4);
+
+/// Stepping forward costs 1 and turning a quarter in place costs 1000.
+fn reindeer_moves(maze: &Grid<Space>, (pos, dir): ReindeerState) -> Vec<(ReindeerState, usize)> {
+    let mut moves = vec![((pos, dir.turn_cw()), 1000), ((pos, dir.turn_ccw()), 1000)];
+    if maze.get(pos + dir) == Some(Space::Empty) {
+        moves.push(((pos + dir, dir), 1));
+    }
+    moves
+}
+
+fn find_min_maze_path_score(
+    maze: &Grid<Space>,
+    start_pos: GridPos,
+    start_dir: Dir4,
+    end_pos: GridPos,
+    verbose: bool,
+) -> Option<usize> {
+    let paths = path::dijkstra(
+        (start_pos, start_dir),
+        |state| reindeer_moves(maze, *state),
+        |(pos, _)| *pos == end_pos,
+    );
+
+    if verbose {
+        let mut min_dist_grid = Grid::new(maze.width, maze.height, None);
+        for ((pos, _), dist) in paths.distances() {
+            let cell = min_dist_grid.get_cell_mut(pos.row, pos.col);
+            *cell = Some(cell.map_or(*dist, |old_dist: usize| old_dist.min(*dist)));
+        }
+        let dist_grid_str = dump_dist_grid(maze, &min_dist_grid);
+        println!("result dist grid:\n{}", dist_grid_str);
+    }
+
+    paths.goal().and_then(|goal| paths.dist(goal))
+}
+
+/// Counts the tiles on any of the cheapest paths to the end, however the reindeer ends up facing.
+fn count_best_path_tiles(
+    maze: &Grid<Space>,
+    start_pos: GridPos,
+    start_dir: Dir4,
+    end_pos: GridPos,
+    verbose: bool,
+) -> Option<usize> {
+    // search everything so every direction the end can be reached from gets its best score
+    let paths = path::dijkstra((start_pos, start_dir), |state| reindeer_moves(maze, *state), |_| false);
+
+    let end_states: Vec<ReindeerState> = Dir4::ALL.iter().map(|dir| (end_pos, *dir)).collect();
+    let min_score = end_states.iter().filter_map(|end_state| paths.dist(end_state)).min()?;
+    let best_end_states = end_states
+        .into_iter()
+        .filter(|end_state| paths.dist(end_state) == Some(min_score));
+    let best_path_tiles = paths.all_shortest_path_cells(best_end_states);
+
+    if verbose {
+        let mut buf = String::with_capacity((maze.width + 1) * maze.height);
+        for r in 0..(maze.height as isize) {
+            for c in 0..(maze.width as isize) {
+                buf.push(if best_path_tiles.contains(&GridPos { row: r, col: c }) {
+                    'O'
+                } else if let Space::Wall = maze.get_cell(r, c) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            buf.push('\n');
+        }
+        println!("best path tiles:\n{}", buf);
+    }
+
+    Some(best_path_tiles.len())
+}
+
+fn options() -> input_helpers::Options {
+    input_helpers::Options::new().flag("verbose", Some('v'), "print the maze with the best scores or best path tiles")
+}
+
+pub struct Day16;
+
+impl solution::Solution for Day16 {
+    type Input = StartingState;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_input(filename)
+    }
+
+    fn part1(starting_state: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let verbose = options().parse(args)?.flag("verbose");
+
+        let StartingState {
+            maze,
+            start_pos,
+            end_pos,
+            starting_dir,
+        } = starting_state;
+
+        println!("Searching...");
+        find_min_maze_path_score(maze, *start_pos, *starting_dir, *end_pos, verbose)
+            .ok_or("no solutions to maze".into())
+    }
+
+    fn part2(starting_state: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let verbose = options().parse(args)?.flag("verbose");
+
+        let StartingState {
+            maze,
+            start_pos,
+            end_pos,
+            starting_dir,
+        } = starting_state;
+
+        count_best_path_tiles(maze, *start_pos, *starting_dir, *end_pos, verbose)
+            .ok_or("no solutions to maze".into())
+    }
+}
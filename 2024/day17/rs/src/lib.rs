@@ -0,0 +1,496 @@
+use input_helpers::ParseError;
+use simple_grid::{Grid, GridPos};
+
+#[derive(Clone, Copy, Debug)]
+pub struct CpuState {
+    instruction_pointer: usize,
+    reg_a: usize,
+    reg_b: usize,
+    reg_c: usize,
+}
+
+impl std::fmt::Display for CpuState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(IP:{}, A:{}, B:{}, C:{})", 
+            self.instruction_pointer,
+            self.reg_a,
+            self.reg_b,
+            self.reg_c)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum LiteralOperand {
+    Lit0,
+    Lit1,
+    Lit2,
+    Lit3,
+    Lit4,
+    Lit5,
+    Lit6,
+    Lit7,
+}
+
+impl LiteralOperand {
+    fn from_char(c: char) -> Result<Self, String> {
+        let op = match c {
+            '0' => LiteralOperand::Lit0,
+            '1' => LiteralOperand::Lit1,
+            '2' => LiteralOperand::Lit2,
+            '3' => LiteralOperand::Lit3,
+            '4' => LiteralOperand::Lit4,
+            '5' => LiteralOperand::Lit5,
+            '6' => LiteralOperand::Lit6,
+            '7' => LiteralOperand::Lit7,
+            _ => return Err(format!("Invalid literal op '{}'", c)),
+        };
+
+        Ok(op)
+    }
+
+    fn to_char(&self) -> char {
+        match *self {
+            LiteralOperand::Lit0 => '0',
+            LiteralOperand::Lit1 => '1',
+            LiteralOperand::Lit2 => '2',
+            LiteralOperand::Lit3 => '3',
+            LiteralOperand::Lit4 => '4',
+            LiteralOperand::Lit5 => '5',
+            LiteralOperand::Lit6 => '6',
+            LiteralOperand::Lit7 => '7',
+        }
+    }
+    
+    fn value(&self) -> usize {
+        match *self {
+            LiteralOperand::Lit0 => 0,
+            LiteralOperand::Lit1 => 1,
+            LiteralOperand::Lit2 => 2,
+            LiteralOperand::Lit3 => 3,
+            LiteralOperand::Lit4 => 4,
+            LiteralOperand::Lit5 => 5,
+            LiteralOperand::Lit6 => 6,
+            LiteralOperand::Lit7 => 7,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum ComboOperand {
+    Lit0,
+    Lit1,
+    Lit2,
+    Lit3,
+    RegA,
+    RegB,
+    RegC,
+}
+
+impl ComboOperand {
+    fn from_char(c: char) -> Result<Self, String> {
+        let op = match c {
+            '0' => ComboOperand::Lit0,
+            '1' => ComboOperand::Lit1,
+            '2' => ComboOperand::Lit2,
+            '3' => ComboOperand::Lit3,
+            '4' => ComboOperand::RegA,
+            '5' => ComboOperand::RegB,
+            '6' => ComboOperand::RegC,
+            '7' => return Err(String::from("Combo operator 7 is reserved and invalid")),
+            _ => return Err(format!("Invalid literal op '{}'", c)),
+        };
+
+        Ok(op)
+    }
+
+    fn to_char(&self) -> char {
+        match *self {
+            ComboOperand::Lit0 => '0',
+            ComboOperand::Lit1 => '1',
+            ComboOperand::Lit2 => '2',
+            ComboOperand::Lit3 => '3',
+            ComboOperand::RegA => '4',
+            ComboOperand::RegB => '5',
+            ComboOperand::RegC => '6',
+        }
+    }
+
+    fn encode(&self) -> usize {
+        match *self {
+            ComboOperand::Lit0 => 0,
+            ComboOperand::Lit1 => 1,
+            ComboOperand::Lit2 => 2,
+            ComboOperand::Lit3 => 3,
+            ComboOperand::RegA => 4,
+            ComboOperand::RegB => 5,
+            ComboOperand::RegC => 6,
+        }
+    }
+}
+
+fn read_combo_operand(op: ComboOperand, cpu: &CpuState) -> usize {
+   match op {
+        ComboOperand::Lit0 => 0,
+        ComboOperand::Lit1 => 1,
+        ComboOperand::Lit2 => 2,
+        ComboOperand::Lit3 => 3,
+        ComboOperand::RegA => cpu.reg_a,
+        ComboOperand::RegB => cpu.reg_b,
+        ComboOperand::RegC => cpu.reg_c,
+   } 
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AdvInstr {
+    op: ComboOperand,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BxlInstr {
+    op: LiteralOperand,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BstInstr {
+    op: ComboOperand,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct JnzInstr {
+    op: LiteralOperand,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BxcInstr {
+    ignored_op: LiteralOperand,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OutInstr {
+    op: ComboOperand,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BdvInstr {
+    op: ComboOperand,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CdvInstr {
+    op: ComboOperand,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Instr {
+    Adv(AdvInstr), // Op(0): RegA = RegA / 2^(op)
+    Bxl(BxlInstr), // Op(1): RegB = RegB ^ (lit)
+    Bst(BstInstr), // Op(2): RegB = (op) % 8
+    Jnz(JnzInstr), // Op(3): if (RegA!=0) *ip = (lit)
+    Bxc(BxcInstr), // Op(4): RegB = RegB ^ RegC    
+    Out(OutInstr), // Op(5): [output] (op) % 8 
+    Bdv(BdvInstr), // Op(6): RegB = RegA / 2^(op)
+    Cdv(CdvInstr), // Op(7): RegC = RegA / 2^(op)
+}
+
+fn do_adv_instr(adv: &AdvInstr, cpu: &mut CpuState) {
+    let op = read_combo_operand(adv.op, cpu);
+    let divisor = (2_usize).pow(op as u32);
+    cpu.reg_a = cpu.reg_a / divisor;
+    cpu.instruction_pointer += 2;
+}
+
+fn do_bxl_instr(bxl: &BxlInstr, cpu: &mut CpuState) {
+    cpu.reg_b ^= bxl.op.value();
+    cpu.instruction_pointer += 2;
+}
+
+fn do_bst_instr(bst: &BstInstr, cpu: &mut CpuState) {
+    let op = read_combo_operand(bst.op, cpu);
+    cpu.reg_b = op % 8;
+    cpu.instruction_pointer += 2;
+}
+
+fn do_jnz_instr(jnz: &JnzInstr, cpu: &mut CpuState) {
+    if cpu.reg_a == 0 {
+        cpu.instruction_pointer += 2;
+    } else {
+        cpu.instruction_pointer = jnz.op.value();
+    }
+}
+
+fn do_bxc_instr(cpu: &mut CpuState) {
+    cpu.reg_b ^= cpu.reg_c;
+    cpu.instruction_pointer += 2;
+}
+
+fn do_out_instr(out: &OutInstr, cpu: &mut CpuState) -> usize {
+    let op = read_combo_operand(out.op, cpu);
+    let res = op % 8;
+    cpu.instruction_pointer += 2;
+    res
+}
+
+fn do_bdv_instr(bdv: &BdvInstr, cpu: &mut CpuState) {
+    let op = read_combo_operand(bdv.op, cpu);
+    let divisor = (2_usize).pow(op as u32);
+    cpu.reg_b = cpu.reg_a / divisor;
+    cpu.instruction_pointer += 2;
+}
+
+fn do_cdv_instr(cdv: &CdvInstr, cpu: &mut CpuState) {
+    let op = read_combo_operand(cdv.op, cpu);
+    let divisor = (2_usize).pow(op as u32);
+    cpu.reg_c = cpu.reg_a / divisor;
+    cpu.instruction_pointer += 2;
+}
+
+fn encode_instructions(instructions: &[Instr]) -> Vec<usize> {
+    let mut encoded_instructions = Vec::with_capacity(instructions.len() * 2);
+    for instruction in instructions {
+        let (opcode, operand) = match instruction {
+            Instr::Adv(adv) => (0, adv.op.encode()),
+            Instr::Bxl(bxl) => (1, bxl.op.value()),
+            Instr::Bst(bst) => (2, bst.op.encode()),
+            Instr::Jnz(jnz) => (3, jnz.op.value()),
+            Instr::Bxc(bxc) => (4, bxc.ignored_op.value()),
+            Instr::Out(out) => (5, out.op.encode()),
+            Instr::Bdv(bdv) => (6, bdv.op.encode()),
+            Instr::Cdv(cdv) => (7, cdv.op.encode()),
+        };
+        encoded_instructions.push(opcode);
+        encoded_instructions.push(operand);
+    }
+
+    encoded_instructions
+}
+
+fn parse_register_line(line_number: usize, line: &str, reg_name: char) -> Result<usize, ParseError> {
+    let (reg_val,) = input_helpers::scan!(line_number, line, &format!("Register {}: {{}}", reg_name), usize)?;
+    Ok(reg_val)
+}
+
+fn read_initial_cpu_state(filename: &str) -> Result<(CpuState, Vec<Instr>), String> {
+    let lines: Vec<String> = input_helpers::try_read_all_lines(filename)?;
+    parse_initial_cpu_state(&lines).map_err(|e| e.with_file(filename).into())
+}
+
+fn parse_initial_cpu_state(lines: &[String]) -> Result<(CpuState, Vec<Instr>), ParseError> {
+    const EXPECTED_LINES: &str = "5 lines: registers A, B and C, an empty line, then the program";
+    if lines.len() < 5 {
+        return Err(ParseError::whole_line(lines.len() + 1, "", EXPECTED_LINES));
+    }
+    if lines.len() > 5 {
+        return Err(ParseError::whole_line(6, &lines[5], EXPECTED_LINES));
+    }
+
+    let reg_a_line = &lines[0];
+    let reg_b_line = &lines[1];
+    let reg_c_line = &lines[2];
+    let separator_line = &lines[3];
+    let program_line = &lines[4];
+
+    if separator_line != "" {
+        return Err(ParseError::whole_line(4, separator_line, "an empty separator line"));
+    }
+
+    let cpu_state = {
+        let reg_a = parse_register_line(1, &reg_a_line, 'A')?;
+        let reg_b = parse_register_line(2, &reg_b_line, 'B')?;
+        let reg_c = parse_register_line(3, &reg_c_line, 'C')?;
+        CpuState { instruction_pointer: 0, reg_a, reg_b, reg_c }
+    };
+
+    const PROGRAM_LINE_PREFIX: &str = "Program: ";
+    let program_data = program_line.strip_prefix(PROGRAM_LINE_PREFIX).ok_or_else(|| {
+        ParseError::whole_line(5, program_line, &format!("a program line starting with '{}'", PROGRAM_LINE_PREFIX))
+    })?;
+
+    let mut instructions = vec![];
+
+    let verify_one_char = |s: &str| -> Result<char, ParseError> {
+        let mut s_chars = s.chars();
+        match (s_chars.next(), s_chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(ParseError::at_substr(5, program_line, s, "a single char")),
+        }
+    };
+
+    let mut split_iter = program_data.split(',');
+    while let Some(next_operation) = split_iter.next() {
+        let next_operator_char = verify_one_char(next_operation)?;
+
+        let next_operand = split_iter.next().ok_or_else(|| {
+            ParseError::new(5, program_line, program_line.len()..program_line.len(),
+                &format!("an operand for operator {}", next_operator_char))
+        })?;
+        let next_operand_char = verify_one_char(next_operand)?;
+        let operand_err = |e: String| ParseError::at_substr(5, program_line, next_operand, &format!("a valid operand ({})", e));
+
+        let instr = match next_operator_char {
+            '0' => { 
+                let op = ComboOperand::from_char(next_operand_char).map_err(operand_err)?;
+                Instr::Adv(AdvInstr{op}) 
+            },
+            '1' => { 
+                let op = LiteralOperand::from_char(next_operand_char).map_err(operand_err)?;
+                Instr::Bxl(BxlInstr{op}) 
+            },
+            '2' => { 
+                let op = ComboOperand::from_char(next_operand_char).map_err(operand_err)?;
+                Instr::Bst(BstInstr{op}) 
+            },
+            '3' => { 
+                let op = LiteralOperand::from_char(next_operand_char).map_err(operand_err)?;
+                Instr::Jnz(JnzInstr{op}) 
+            },
+            '4' => { 
+                let ignored_op = LiteralOperand::from_char(next_operand_char).map_err(operand_err)?;
+                Instr::Bxc(BxcInstr{ignored_op})
+            },
+            '5' => { 
+                let op = ComboOperand::from_char(next_operand_char).map_err(operand_err)?;
+                Instr::Out(OutInstr{op}) 
+            },
+            '6' => { 
+                let op = ComboOperand::from_char(next_operand_char).map_err(operand_err)?;
+                Instr::Bdv(BdvInstr{op}) 
+            },
+            '7' => { 
+                let op = ComboOperand::from_char(next_operand_char).map_err(operand_err)?;
+                Instr::Cdv(CdvInstr{op}) 
+            },
+            _ => return Err(ParseError::at_substr(5, program_line, next_operation, "an operator from 0 to 7")),
+        };
+
+        instructions.push(instr);
+    }
+
+    Ok((cpu_state, instructions))
+}
+
+fn do_next_instruction(cpu: &mut CpuState, instructions: &[Instr]) -> Result<Option<usize>, String> {
+    if cpu.instruction_pointer % 2 != 0 {
+        return Err(format!("instruction pointer @ {} not pointing to valid instruction", cpu.instruction_pointer));
+    }
+
+    let next_instruction = instructions[cpu.instruction_pointer / 2];
+    match next_instruction {
+        Instr::Adv(adv) => {
+            do_adv_instr(&adv, cpu);
+            Ok(None)
+        },
+
+        Instr::Bxl(bxl) => {
+            do_bxl_instr(&bxl, cpu);
+            Ok(None)
+        },
+
+        Instr::Bst(bst) => {
+            do_bst_instr(&bst, cpu);
+            Ok(None)
+        },
+
+        Instr::Jnz(jnz) => {
+            do_jnz_instr(&jnz, cpu);
+            Ok(None)
+        },
+
+        Instr::Bxc(_) => {
+            do_bxc_instr(cpu);
+            Ok(None)
+        },
+
+        Instr::Out(out) => {
+            let output = do_out_instr(&out, cpu);
+            Ok(Some(output))
+        },
+
+        Instr::Bdv(bdv) => {
+            do_bdv_instr(&bdv, cpu);
+            Ok(None)
+        },
+
+        Instr::Cdv(cdv) => {
+            do_cdv_instr(&cdv, cpu);
+            Ok(None)
+        },
+    }
+}
+
+fn options() -> input_helpers::Options {
+    input_helpers::Options::new().flag("verbose", Some('v'), "print every reg_a value tried")
+}
+
+pub struct Day17;
+
+impl solution::Solution for Day17 {
+    type Input = (CpuState, Vec<Instr>);
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_initial_cpu_state(filename)
+    }
+
+    fn part1((original_cpu_state, instructions): &Self::Input, args: &[String]) -> Result<String, solution::Error> {
+        options().parse(args)?;
+
+        dbg!(original_cpu_state);
+        dbg!(instructions);
+
+        let mut cpu_state = original_cpu_state.clone();
+        let mut output = vec![];
+        while cpu_state.instruction_pointer < (instructions.len() * 2) {
+            let instr_output = do_next_instruction(&mut cpu_state, instructions)?;
+            if let Some(instr_output) = instr_output {
+                output.push(instr_output);
+            }
+        }
+
+        let output_str = output
+            .iter()
+            .map(|output_val| output_val.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        println!("CPU: {:?}", cpu_state);
+
+        Ok(output_str)
+    }
+
+    fn part2((original_cpu_state, instructions): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let verbose = options().parse(args)?.flag("verbose");
+
+        let correct_output = encode_instructions(instructions);
+        let mut modified_reg_a = 0;
+        loop {
+            if verbose || (modified_reg_a % 100_000 == 0){
+                println!("Testing reg_a={} for pt 2", modified_reg_a);
+            }
+
+            let mut cpu_state = original_cpu_state.clone();
+            cpu_state.reg_a = modified_reg_a;
+            let mut output = vec![];
+            while cpu_state.instruction_pointer < (instructions.len() * 2) {
+                let instr_output = do_next_instruction(&mut cpu_state, instructions)?;
+                if let Some(instr_output) = instr_output {
+                    output.push(instr_output);
+                }
+            }
+
+            if output == correct_output {
+                let output_str = output
+                    .iter()
+                    .map(|output_val| output_val.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",");
+
+                println!("CPU: {:?}", cpu_state);
+                println!("output: {}", output_str);
+                return Ok(modified_reg_a);
+            }
+
+            modified_reg_a += 1;
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use core::fmt;
+use input_helpers;
+use simple_grid::{path, Grid, GridPos};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Space {
+    Safe,
+    Corrupted,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+enum DijDist {
+    Dist(usize),
+    Inf,
+}
+
+fn read_input(filename: &str) -> Result<(Grid<Space>, Vec<GridPos>), String> {
+    let lines: Vec<String> = input_helpers::try_read_all_lines(filename)?;
+
+    if lines.len() < 2 {
+        return Err(format!(
+            "Invalid input! Require at least 2 lines. Had {}",
+            lines.len()
+        ));
+    }
+
+    let (width, height) = {
+        let dimension_line = &lines[0];
+        let mut dimension_line_split = dimension_line.split_ascii_whitespace();
+        let width_str = dimension_line_split
+            .next()
+            .ok_or(String::from("Missing width value on first line"))?;
+        let height_str = dimension_line_split
+            .next()
+            .ok_or(String::from("Missing height value on first line"))?;
+        if let Some(v) = dimension_line_split.next() {
+            return Err(format!(
+                "Unexpected values on first line after height: {}! '{}'",
+                v, dimension_line
+            ));
+        }
+
+        let width: isize = width_str
+            .parse()
+            .map_err(|_| format!("couldn't parse width value! '{}'", width_str))?;
+        let height: isize = height_str
+            .parse()
+            .map_err(|_| format!("couldn't parse height value! '{}'", height_str))?;
+        (width, height)
+    };
+
+    if lines[1] != "" {
+        return Err(format!(
+            "line 1 should be an empty separator! found {}",
+            lines[1]
+        ));
+    }
+
+    let corrupted_position_lines = &lines[2..];
+    let mut corrupted_positions = vec![];
+
+    for (i, pos_line) in corrupted_position_lines.iter().enumerate() {
+        let line_num = i + 2;
+        let mut pos_line_split = pos_line.split(',');
+        let x_str = pos_line_split.next().ok_or(format!(
+            "Missing x value on line {}! {}",
+            line_num, pos_line
+        ))?;
+        let y_str = pos_line_split.next().ok_or(format!(
+            "Missing y value on line {}! {}",
+            line_num, pos_line
+        ))?;
+        if let Some(v) = pos_line_split.next() {
+            return Err(format!(
+                "Unexpected values on line {} after y value: {}! '{}'",
+                line_num, v, pos_line
+            ));
+        }
+
+        let x: isize = x_str
+            .parse()
+            .map_err(|_| format!("couldn't parse x value! '{}'", x_str))?;
+        let y: isize = y_str
+            .parse()
+            .map_err(|_| format!("couldn't parse y value! '{}'", y_str))?;
+        let corrupted_pos = GridPos { row: y, col: x };
+        corrupted_positions.push(corrupted_pos);
+    }
+
+    let memory_safety_grid = Grid::new(width as usize, height as usize, Space::Safe);
+
+    Ok((memory_safety_grid, corrupted_positions))
+}
+
+fn dump_memory_safety_grid(memory_grid: &Grid<Space>) -> String {
+    let mut buf = String::with_capacity((memory_grid.width + 1) * memory_grid.height);
+    for r in 0..(memory_grid.height as isize) {
+        for c in 0..(memory_grid.width as isize) {
+            let cell_char = match memory_grid.get_cell(r, c) {
+                Space::Safe => '.',
+                Space::Corrupted => '#',
+            };
+            buf.push(cell_char);
+        }
+        buf.push('\n');
+    }
+    buf
+}
+
+fn print_memory_safety_grid(title: Option<&str>, memory_grid: &Grid<Space>) {
+    if let Some(title) = title {
+        println!("{}: ", title);
+    }
+    println!("{}", dump_memory_safety_grid(memory_grid));
+}
+
+fn dump_dist_grid(memory_grid: &Grid<Space>, dist_tracker: &Grid<DijDist>) -> String {
+    fn count_digits(n: usize) -> usize {
+        let mut n = n;
+        let mut digit_count = 1;
+        while n > 9 {
+            n /= 10;
+            digit_count += 1;
+        }
+        digit_count
+    }
+
+    let max_digit_count = dist_tracker
+        .cells
+        .iter()
+        .map(|dist| {
+            match dist {
+                DijDist::Dist(d) => count_digits(*d),
+                DijDist::Inf => 3, // "Inf"
+            }
+        })
+        .max()
+        .unwrap_or(0);
+
+    let corrupted_cell_str = {
+        let mut buf = String::new();
+        buf.push('[');
+        for _ in 0..max_digit_count {
+            buf.push('#');
+        }
+        buf.push(']');
+        buf
+    };
+
+    let inf_cell_str = {
+        assert!(max_digit_count >= "INF".len());
+        let mut buf = String::new();
+        buf.push('[');
+        for _ in 0..(max_digit_count / 2 - 1) {
+            buf.push(' ');
+        }
+        buf.push('I');
+        buf.push('N');
+        buf.push('F');
+        let remaining_digit_spaces = max_digit_count - (max_digit_count / 2 + 3 - 1);
+        for _ in 0..remaining_digit_spaces {
+            buf.push(' ');
+        }
+        buf.push(']');
+        buf
+    };
+
+    fn fmt_num_cell(n: usize, max_digit_count: usize) -> String {
+        let digit_count = count_digits(n);
+        assert!(max_digit_count >= digit_count);
+        let mut buf = String::new();
+        buf.push('[');
+        for _ in 0..(max_digit_count / 2 - digit_count / 2) {
+            buf.push(' ');
+        }
+        buf.push_str(&n.to_string());
+        let remaining_digit_spaces =
+            max_digit_count - (max_digit_count / 2 - digit_count / 2) - (digit_count);
+        for _ in 0..remaining_digit_spaces {
+            buf.push(' ');
+        }
+        buf.push(']');
+        buf
+    }
+
+    let mut buf = String::with_capacity((memory_grid.width + 1) * memory_grid.height);
+    for r in 0..(memory_grid.height as isize) {
+        for c in 0..(memory_grid.width as isize) {
+            let cell_str = match memory_grid.get_cell(r, c) {
+                Space::Safe => match dist_tracker.get_cell(r, c) {
+                    DijDist::Dist(dist) => fmt_num_cell(dist, max_digit_count),
+                    DijDist::Inf => inf_cell_str.clone(),
+                },
+                Space::Corrupted => corrupted_cell_str.clone(),
+            };
+            buf.push_str(&cell_str);
+        }
+        buf.push('\n');
+    }
+    buf
+}
+
+fn corrupt_bytes(memory_grid: &mut Grid<Space>, byte_positions_to_corrupt: &[GridPos]) {
+    for byte_pos in byte_positions_to_corrupt {
+        *memory_grid.get_cell_mut(byte_pos.row, byte_pos.col) = Space::Corrupted;
+    }
+}
+
+fn find_min_safe_path(
+    memory_grid: &Grid<Space>,
+    start_pos: GridPos,
+    end_pos: GridPos,
+    verbose: bool,
+) -> Option<Vec<GridPos>> {
+    let paths = path::bfs(
+        start_pos,
+        |pos| {
+            memory_grid
+                .neighbors4(*pos)
+                .filter(|neighbor_pos| memory_grid.get(*neighbor_pos) == Some(Space::Safe))
+                .collect::<Vec<_>>()
+        },
+        |pos| *pos == end_pos,
+    );
+
+    if verbose {
+        let mut dist_tracker = Grid::new(memory_grid.width, memory_grid.height, DijDist::Inf);
+        for (pos, dist) in paths.distances() {
+            *dist_tracker.get_cell_mut(pos.row, pos.col) = DijDist::Dist(*dist);
+        }
+        let dist_grid_str = dump_dist_grid(memory_grid, &dist_tracker);
+        println!("result grid distances:");
+        print!("{}", dist_grid_str);
+    }
+
+    paths.path_to_goal()
+}
+
+fn options() -> input_helpers::Options {
+    input_helpers::Options::new()
+        .value("bytes", 1024, "how many bytes have fallen by pt 1")
+        .flag("verbose", Some('v'), "print the memory grid and each corrupted byte")
+}
+
+pub struct Day18;
+
+impl solution::Solution for Day18 {
+    type Input = (Grid<Space>, Vec<GridPos>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_input(filename)
+    }
+
+    fn part1((initial_memory_safety_grid, corrupted_bytes): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let options = options().parse(args)?;
+        let num_bytes_to_simulate_in_pt1: usize = options.get("bytes")?;
+        if num_bytes_to_simulate_in_pt1 > corrupted_bytes.len() {
+            return Err(format!(
+                "Only {} bytes fall; can't simulate {}",
+                corrupted_bytes.len(),
+                num_bytes_to_simulate_in_pt1
+            ).into());
+        }
+        let verbose = options.flag("verbose");
+
+        let start_pos = GridPos { row: 0, col: 0 };
+        let end_pos = GridPos {
+            row: (initial_memory_safety_grid.height - 1) as isize,
+            col: (initial_memory_safety_grid.width - 1) as isize,
+        };
+
+        let mut corrupted_memory_grid = initial_memory_safety_grid.clone();
+        corrupt_bytes(
+            &mut corrupted_memory_grid,
+            &corrupted_bytes[..num_bytes_to_simulate_in_pt1],
+        );
+
+        if verbose {
+            print_memory_safety_grid(Some("memory after corruption"), &corrupted_memory_grid);
+        }
+
+        let min_safe_path = find_min_safe_path(&corrupted_memory_grid, start_pos, end_pos, verbose);
+        min_safe_path
+            .map(|min_safe_path| min_safe_path.len() - 1)
+            .ok_or("no path to the exit".into())
+    }
+
+    fn part2((initial_memory_safety_grid, corrupted_bytes): &Self::Input, args: &[String]) -> Result<String, solution::Error> {
+        let verbose = options().parse(args)?.flag("verbose");
+
+        let start_pos = GridPos { row: 0, col: 0 };
+        let end_pos = GridPos {
+            row: (initial_memory_safety_grid.height - 1) as isize,
+            col: (initial_memory_safety_grid.width - 1) as isize,
+        };
+
+        let mut corrupted_memory_grid = initial_memory_safety_grid.clone();
+
+        let mut min_path = find_min_safe_path(&corrupted_memory_grid, start_pos, end_pos, verbose)
+            .expect(
+            "Initial memory grid should be uncorrupted so there must be a path from start to end",
+        );
+
+        let mut first_blocking_byte = None;
+        for (corrupted_byte_idx, corrupted_byte_pos) in corrupted_bytes.iter().enumerate() {
+            // corrupt the next byte
+            if verbose {
+                println!(
+                    "corrupting byte #{} @ {}",
+                    corrupted_byte_idx, corrupted_byte_pos
+                );
+            }
+            corrupt_bytes(
+                &mut corrupted_memory_grid,
+                &corrupted_bytes[corrupted_byte_idx..corrupted_byte_idx + 1],
+            );
+
+            // if the corrupted byte blocked our path, recalculate it.
+            if min_path.contains(corrupted_byte_pos) {
+                if verbose {
+                    println!(
+                        "    corrupted byte @ {} blocked path. Recalculating path",
+                        corrupted_byte_pos
+                    );
+                }
+
+                if let Some(new_min_path) =
+                    find_min_safe_path(&corrupted_memory_grid, start_pos, end_pos, verbose)
+                {
+                    min_path = new_min_path;
+                    if verbose {
+                        println!("    new path found");
+                    }
+                } else {
+                    if verbose {
+                        println!(
+                            "    no new path found! corrupting byte @ {} has blocked path",
+                            corrupted_byte_pos
+                        );
+                    }
+                    first_blocking_byte = Some((corrupted_byte_idx, corrupted_byte_pos));
+                    break;
+                }
+            }
+        }
+
+        if let Some((first_blocking_byte_idx, first_blocking_byte_pos)) = first_blocking_byte {
+            println!(
+                "byte #{} @ {} blocked end",
+                first_blocking_byte_idx, first_blocking_byte_pos
+            );
+            Ok(format!(
+                "{},{}",
+                first_blocking_byte_pos.col, first_blocking_byte_pos.row
+            ))
+        } else {
+            Err("no corrupted bytes ever blocked path".into())
+        }
+    }
+}
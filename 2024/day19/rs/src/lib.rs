@@ -0,0 +1,226 @@
+use input_helpers;
+use std::{env::var};
+
+pub type TowelPattern = String;
+pub type TargetDesign = String;
+
+fn find_invalid_stripe(stripe_seq: &str) -> Option<char> {
+    for stripe_char in stripe_seq.chars() {
+        match stripe_char {
+            'w' | 'u' | 'b' | 'r' | 'g' => continue, // valid towel patterns
+            _ => return Some(stripe_char),
+        }
+    }
+
+    None
+}
+
+fn is_valid_stripe_sequence(stripe_seq: &str) -> bool {
+    find_invalid_stripe(stripe_seq).is_none()
+}
+
+fn read_input(filename: &str) -> Result<(Vec<TowelPattern>, Vec<TargetDesign>), String> {
+    let lines: Vec<String> = input_helpers::try_read_all_lines(filename)?;
+
+    let (pattern_section, design_section) =
+        input_helpers::two_sections(&lines, "the towel patterns", "the target designs")
+            .map_err(|e| e.with_file(filename))?;
+    if pattern_section.lines.len() != 1 {
+        return Err(String::from("The towel patterns must be on 1 line"));
+    }
+
+    let towel_patterns: Vec<String> = pattern_section.lines[0].split(", ").map(|s| s.to_string()).collect();
+    for towel_pattern in &towel_patterns {
+        if let Some(invalid_stripe_char) = find_invalid_stripe(&towel_pattern) {
+            return Err(format!(
+                "Invalid towel pattern char '{}'",
+                invalid_stripe_char
+            ));
+        }
+    }
+
+    let target_designs: Vec<String> = design_section.lines.to_vec();
+
+    Ok((towel_patterns, target_designs))
+}
+
+type DesignTestMemoizer = std::collections::HashMap<String, bool>;
+fn is_target_design_possible(
+    target_design: &str,
+    available_patterns: &[TowelPattern],
+    memo: &mut DesignTestMemoizer,
+) -> bool {
+    if !is_valid_stripe_sequence(target_design) {
+        return false;
+    }
+
+    fn is_target_design_possible_helper(
+        target_design: &str,
+        available_patterns: &[TowelPattern],
+        memo: &mut DesignTestMemoizer,
+    ) -> bool {
+        if target_design == "" {
+            return true;
+        }
+
+        if let Some(is_design_possible_memo) = memo.get(target_design) {
+            return *is_design_possible_memo;
+        }
+
+        for available_pattern in available_patterns {
+            if target_design.starts_with(available_pattern) {
+                let design_possible = is_target_design_possible_helper(
+                    &target_design[available_pattern.len()..],
+                    available_patterns,
+                    memo,
+                );
+                if design_possible {
+                    memo.insert(target_design.to_string(), true);
+                    return true;
+                }
+            }
+        }
+
+        memo.insert(target_design.to_string(), false);
+        false
+    }
+
+    println!("Testing {}", target_design);
+    is_target_design_possible_helper(target_design, available_patterns, memo)
+}
+
+type DesignVariantMemoizer = std::collections::HashMap<String, usize>;
+
+fn count_and_memo_possible_target_design_variants(
+    target_design: &str,
+    available_patterns: &[TowelPattern],
+    variant_memo: &mut DesignVariantMemoizer,
+) -> usize {
+    if !is_valid_stripe_sequence(target_design) {
+        return 0;
+    }
+
+    fn count_possible_target_design_variants_helper(
+        target_design: &str,
+        available_patterns: &[TowelPattern],
+        variant_memo: &mut DesignVariantMemoizer,
+    ) -> usize {
+        if target_design == "" {
+            return 1;
+        }
+
+        if let Some(memod_count) = variant_memo.get(target_design) {
+            return *memod_count;
+        }
+
+        let mut possible_design_count = 0;
+        for available_pattern in available_patterns {
+            if target_design.starts_with(available_pattern) {
+                possible_design_count += count_possible_target_design_variants_helper(
+                    &target_design[available_pattern.len()..],
+                    available_patterns,
+                    variant_memo,
+                );
+            }
+        }
+
+        variant_memo.insert(target_design.to_string(), possible_design_count);
+        possible_design_count
+    }
+
+    count_possible_target_design_variants_helper(target_design, available_patterns, variant_memo)
+}
+
+fn options() -> input_helpers::Options {
+    input_helpers::Options::new().flag("verbose", Some('v'), "print the possible designs")
+}
+
+pub struct Day19;
+
+impl solution::Solution for Day19 {
+    type Input = (Vec<TowelPattern>, Vec<TargetDesign>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_input(filename)
+    }
+
+    fn part1((available_patterns, target_designs): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let verbose = options().parse(args)?.flag("verbose");
+
+        dbg!(available_patterns);
+        dbg!(target_designs);
+
+        let possible_designs = {
+            let mut design_test_memo = DesignTestMemoizer::new();
+            let possible_designs: Vec<TargetDesign> = target_designs
+                .iter()
+                .filter(|design| {
+                    is_target_design_possible(design, available_patterns, &mut design_test_memo)
+                })
+                .cloned()
+                .collect();
+
+            if verbose {
+                println!("possible designs:");
+                for design in &possible_designs {
+                    println!("  - {}", design);
+                }
+            }
+
+            possible_designs
+        };
+
+        Ok(possible_designs.len())
+    }
+
+    fn part2((available_patterns, target_designs): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let verbose = options().parse(args)?.flag("verbose");
+
+        let possible_designs = {
+            let mut design_test_memo = DesignTestMemoizer::new();
+            let possible_designs: Vec<TargetDesign> = target_designs
+                .iter()
+                .filter(|design| {
+                    is_target_design_possible(design, available_patterns, &mut design_test_memo)
+                })
+                .cloned()
+                .collect();
+
+            println!("Pt 1: {} designs possible", possible_designs.len());
+            if verbose {
+                println!("possible designs:");
+                for design in &possible_designs {
+                    println!("  - {}", design);
+                }
+            }
+
+            possible_designs
+        };
+
+        let mut variant_count_memo = DesignVariantMemoizer::new();
+        let possible_design_variant_counts: Vec<usize> = possible_designs
+            .iter()
+            .map(|design| {
+                count_and_memo_possible_target_design_variants(
+                    &design,
+                    available_patterns,
+                    &mut variant_count_memo,
+                )
+            })
+            .collect();
+        let sum_total_design_variant_counts: usize = possible_design_variant_counts.iter().sum();
+        if verbose {
+            println!("variant counts:");
+            for (design, variant_count) in possible_designs
+                .iter()
+                .zip(possible_design_variant_counts.iter())
+            {
+                println!("  - ({}) {}", variant_count, design);
+            }
+        }
+
+        Ok(sum_total_design_variant_counts)
+    }
+}
@@ -0,0 +1,259 @@
+use input_helpers::ParseError;
+use regex::{Captures, Match, Regex};
+use std::sync::LazyLock;
+
+static WIRE_VALUE_LINE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\w{3}): (\w+)$").expect("wire value regex is valid"));
+static OPERATION_LINE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(\w{3}) (\w+) (\w{3}) -> (\w{3})$").expect("operation regex is valid")
+});
+
+fn options() -> input_helpers::Options {
+    input_helpers::Options::new().value("pair_count", 4, "how many pairs of output wires are swapped")
+}
+
+pub struct Day24;
+
+impl solution::Solution for Day24 {
+    type Input = (WireValues, Vec<Operation>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_input(filename)
+    }
+
+    fn part1((initial_wire_states, operations): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        options().parse(args)?;
+
+        dbg!(initial_wire_states);
+        dbg!(operations);
+
+        let result_wire_values = run_wire_operations(operations, initial_wire_states);
+        let z_value = sum_wire_bits_as_binary_value('z', &result_wire_values);
+
+        Ok(z_value)
+    }
+
+    fn part2((initial_wire_states, operations): &Self::Input, args: &[String]) -> Result<String, solution::Error> {
+        let pair_count: usize = options().parse(args)?.get("pair_count")?;
+
+        let swapped_wire_names: Vec<String> = {
+            let swapped_wire_pairs = find_pt2_wire_pairs(pair_count, operations, initial_wire_states);
+            let mut swapped_wires = vec![];
+            for (wire_a, wire_b) in &swapped_wire_pairs {
+                swapped_wires.push(wire_a.as_str());
+                swapped_wires.push(wire_b.as_str());
+            }
+
+            swapped_wires.sort();
+
+            swapped_wires.iter().map(|s| s.to_string()).collect()
+        };
+
+        let pt2_result = swapped_wire_names.join(",");
+
+        Ok(pt2_result)
+    }
+}
+
+pub type WireValues = std::collections::HashMap<String, bool>;
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    op: OperationType,
+    wire_a: String,
+    wire_b: String,
+    result_wire: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum OperationType {
+    And,
+    Or,
+    Xor,
+}
+
+fn read_input(filename: &str) -> Result<(WireValues, Vec<Operation>), String> {
+    let lines: Vec<String> = input_helpers::try_read_all_lines(filename)?;
+    parse_input(&lines).map_err(|e| e.with_file(filename).into())
+}
+
+fn parse_input(lines: &[String]) -> Result<(WireValues, Vec<Operation>), ParseError> {
+    let mut initial_wire_values = WireValues::new();
+    let mut operations = vec![];
+
+    let separator_line_idx = if let Some(separator_line_idx) = lines.iter().position(|l| l == "") {
+        separator_line_idx
+    } else {
+        return Err(ParseError::whole_line(
+            lines.len() + 1,
+            "",
+            "an empty line between the wire values and the operations",
+        ));
+    };
+
+    let init_wire_value_lines = &lines[0..separator_line_idx];
+    for (line_idx, line) in init_wire_value_lines.iter().enumerate() {
+        let line_number = line_idx + 1;
+        let expected = "a wire value like 'x00: 1'";
+        let wire_line_match = WIRE_VALUE_LINE_RE
+            .captures(line)
+            .ok_or_else(|| ParseError::whole_line(line_number, line, expected))?;
+        let wire_match = capture(&wire_line_match, 1, line_number, line, expected)?;
+        let wire = wire_match.as_str();
+        let is_wire_set_match = capture(&wire_line_match, 2, line_number, line, expected)?;
+        let is_wire_set = match is_wire_set_match.as_str() {
+            "1" => true,
+            "0" => false,
+            _ => {
+                return Err(ParseError::new(
+                    line_number,
+                    line,
+                    is_wire_set_match.range(),
+                    "a wire value of 1 or 0",
+                ))
+            }
+        };
+
+        let old_wire_value = initial_wire_values.insert(wire.to_string(), is_wire_set);
+        if old_wire_value.is_some() {
+            return Err(ParseError::new(
+                line_number,
+                line,
+                wire_match.range(),
+                &format!("a wire that hasn't been initialized yet ({} was initialized before)", wire),
+            ));
+        }
+    }
+
+    let operation_lines = &lines[separator_line_idx + 1..];
+
+    for (line_idx, line) in operation_lines.iter().enumerate() {
+        let line_number = separator_line_idx + line_idx + 2;
+        let expected = "an operation like 'x00 AND y00 -> z00'";
+        let operation_line_match = OPERATION_LINE_RE
+            .captures(line)
+            .ok_or_else(|| ParseError::whole_line(line_number, line, expected))?;
+
+        let wire_a = capture(&operation_line_match, 1, line_number, line, expected)?.as_str();
+
+        let operation_type_match = capture(&operation_line_match, 2, line_number, line, expected)?;
+
+        let wire_b = capture(&operation_line_match, 3, line_number, line, expected)?.as_str();
+
+        let result_wire = capture(&operation_line_match, 4, line_number, line, expected)?.as_str();
+
+        let operation_type = match operation_type_match.as_str() {
+            "AND" => OperationType::And,
+            "OR" => OperationType::Or,
+            "XOR" => OperationType::Xor,
+            _ => {
+                return Err(ParseError::new(
+                    line_number,
+                    line,
+                    operation_type_match.range(),
+                    "an operation of AND, OR or XOR",
+                ))
+            }
+        };
+
+        let operation = Operation {
+            op: operation_type,
+            wire_a: wire_a.to_string(),
+            wire_b: wire_b.to_string(),
+            result_wire: result_wire.to_string(),
+        };
+        operations.push(operation);
+    }
+
+    Ok((initial_wire_values, operations))
+}
+
+/// Capture group `group` of a line that matched, or an error covering the whole line if the group
+/// didn't take part in the match.
+fn capture<'l>(
+    captures: &Captures<'l>,
+    group: usize,
+    line_number: usize,
+    line: &str,
+    expected: &str,
+) -> Result<Match<'l>, ParseError> {
+    captures
+        .get(group)
+        .ok_or_else(|| ParseError::whole_line(line_number, line, expected))
+}
+
+fn run_wire_operations(operations: &[Operation], initial_wire_values: &WireValues) -> WireValues {
+    let mut wire_values = initial_wire_values.clone();
+
+    let mut operations_remaining = operations.to_vec();
+
+    // FIXME: rather than looping and relooping, there are smarter ways to process this to run faster.
+    // For every new result, check which operations are affected by that result and do those first
+    while !operations_remaining.is_empty() {
+        let prev_operations_remaining_count = operations_remaining.len();
+
+        let mut next_operation_idx = 0;
+        while next_operation_idx < operations_remaining.len() {
+            let next_operation = &operations_remaining[next_operation_idx];
+
+            // any remaining operations should not write to an already calculated wire value
+            assert!(!wire_values.contains_key(&next_operation.result_wire));
+
+            let mut operation_completed = false;
+            if let Some(wire_a_set) = wire_values.get(&next_operation.wire_a) {
+                if let Some(wire_b_set) = wire_values.get(&next_operation.wire_b) {
+                    let result = match next_operation.op {
+                        OperationType::And => *wire_a_set && *wire_b_set,
+                        OperationType::Or => *wire_a_set || *wire_b_set,
+                        OperationType::Xor => *wire_a_set != *wire_b_set,
+                    };
+
+                    wire_values.insert(next_operation.result_wire.clone(), result);
+                    operation_completed = true;
+                }
+            }
+
+            if operation_completed {
+                operations_remaining.remove(next_operation_idx);
+            } else {
+                next_operation_idx += 1;
+            }
+        }
+
+        // This has to change across loop iterations or we've gotten stuck and aren't making progress.
+        assert!(operations_remaining.len() != prev_operations_remaining_count);
+    }
+
+    wire_values
+}
+
+fn sum_wire_bits_as_binary_value(wire_set_char: char, wire_values: &WireValues) -> usize {
+    let mut next_wire_position = 0;
+    let mut wire_set_value = 0;
+    loop {
+        let next_wire_name = format!("{}{:02}", wire_set_char, next_wire_position);
+        if let Some(next_wire_is_set) = wire_values.get(&next_wire_name) {
+            if *next_wire_is_set {
+                let bit_value = (2 as usize).pow(next_wire_position);
+                wire_set_value += bit_value;
+            }
+        } else {
+            break;
+        }
+        next_wire_position += 1;
+    }
+
+    wire_set_value
+}
+
+fn find_pt2_wire_pairs(
+    pair_count: usize,
+    operations: &[Operation],
+    initial_wire_values: &WireValues,
+) -> Vec<(String, String)> {
+    let mut wire_pairs = vec![];
+
+    wire_pairs
+}
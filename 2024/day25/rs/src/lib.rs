@@ -0,0 +1,203 @@
+use input_helpers::ParseError;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Lock {
+    pin_heights: (u8, u8, u8, u8, u8),
+}
+
+impl std::fmt::Display for Lock {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Lock(pins={},{},{},{},{})",
+            self.pin_heights.0,
+            self.pin_heights.1,
+            self.pin_heights.2,
+            self.pin_heights.3,
+            self.pin_heights.4
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Key {
+    notch_heights: (u8, u8, u8, u8, u8),
+}
+
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Key(notches={},{},{},{},{})",
+            self.notch_heights.0,
+            self.notch_heights.1,
+            self.notch_heights.2,
+            self.notch_heights.3,
+            self.notch_heights.4
+        )
+    }
+}
+
+fn options() -> input_helpers::Options {
+    input_helpers::Options::new().flag("verbose", Some('v'), "print whether each lock and key fit")
+}
+
+pub struct Day25;
+
+impl solution::Solution for Day25 {
+    type Input = (Vec<Lock>, Vec<Key>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(filename: &str) -> Result<Self::Input, String> {
+        read_input(filename)
+    }
+
+    fn part1((locks, keys): &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        let verbose = options().parse(args)?.flag("verbose");
+
+        // dbg!(locks);
+        // dbg!(keys);
+
+        let mut compatible_lock_key_pairs = vec![];
+        for lock in locks {
+            for key in keys {
+                if do_key_lock_pair_fit(lock, key) {
+                    compatible_lock_key_pairs.push((lock, key));
+                    if verbose {
+                        println!("{} and {} fit together!", lock, key);
+                    }
+                } else {
+                    if verbose {
+                        println!("{} and {} do not fit together.", lock, key);
+                    }
+                }
+            }
+        }
+
+        Ok(compatible_lock_key_pairs.len())
+    }
+
+    fn part2(_input: &Self::Input, args: &[String]) -> Result<usize, solution::Error> {
+        options().parse(args)?;
+        Err("Pt 2 is not implemented".into())
+    }
+}
+
+fn read_input(filename: &str) -> Result<(Vec<Lock>, Vec<Key>), String> {
+    let lines: Vec<String> = input_helpers::try_read_all_lines(filename)?;
+    parse_input(&lines).map_err(|e| e.with_file(filename).into())
+}
+
+fn parse_input(lines: &[String]) -> Result<(Vec<Lock>, Vec<Key>), ParseError> {
+    let mut locks = vec![];
+    let mut keys = vec![];
+
+    for section in input_helpers::sections(lines) {
+        if section.lines.len() != 7 {
+            // Point at the first extra line, or the last line if some are missing
+            let bad_line_idx = section.lines.len().min(8) - 1;
+            return Err(ParseError::whole_line(
+                section.first_line_number + bad_line_idx,
+                &section.lines[bad_line_idx],
+                "7 lines for each lock or key, with an empty line in between each",
+            ));
+        }
+
+        let item_lines = section.lines;
+        if item_lines.first().unwrap() == "#####" {
+            let lock = read_lock_lines(section.first_line_number + 1, &item_lines[1..])?;
+            locks.push(lock);
+        } else if item_lines.last().unwrap() == "#####" {
+            let key = read_key_lines(section.first_line_number, &item_lines[..item_lines.len() - 1])?;
+            keys.push(key);
+        } else {
+            return Err(ParseError::whole_line(
+                section.first_line_number,
+                &item_lines[0],
+                "'#####' on the first line of a lock or the last line of a key",
+            ));
+        }
+    }
+
+    Ok((locks, keys))
+}
+
+/// `rows` are (line number, line) pairs, starting from the side the column grows from.
+fn read_column_height<'a>(
+    rows: impl Iterator<Item = (usize, &'a String)>,
+    column_idx: usize,
+) -> Result<u8, ParseError> {
+    let mut column_height = 0;
+
+    for (line_number, row) in rows {
+        match &row[column_idx..column_idx + 1] {
+            "#" => column_height += 1,
+            "." => break,
+            _ => {
+                return Err(ParseError::new(
+                    line_number,
+                    row,
+                    column_idx..column_idx + 1,
+                    "'#' or '.'",
+                ))
+            }
+        }
+    }
+
+    Ok(column_height)
+}
+
+fn verify_item_lines(first_line_number: usize, lines: &[String]) -> Result<(), ParseError> {
+    assert_eq!(lines.len(), 6);
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        if line.len() != 5 || !line.is_ascii() {
+            return Err(ParseError::whole_line(
+                first_line_number + line_idx,
+                line,
+                "5 chars of '#' or '.'",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn read_lock_lines(first_line_number: usize, lines: &[String]) -> Result<Lock, ParseError> {
+    verify_item_lines(first_line_number, lines)?;
+
+    let rows = || lines.iter().enumerate().map(|(idx, line)| (first_line_number + idx, line));
+    let pin_heights = (
+        read_column_height(rows(), 0)?,
+        read_column_height(rows(), 1)?,
+        read_column_height(rows(), 2)?,
+        read_column_height(rows(), 3)?,
+        read_column_height(rows(), 4)?,
+    );
+
+    Ok(Lock { pin_heights })
+}
+
+fn read_key_lines(first_line_number: usize, lines: &[String]) -> Result<Key, ParseError> {
+    verify_item_lines(first_line_number, lines)?;
+
+    let rows = || lines.iter().enumerate().rev().map(|(idx, line)| (first_line_number + idx, line));
+    let notch_heights = (
+        read_column_height(rows(), 0)?,
+        read_column_height(rows(), 1)?,
+        read_column_height(rows(), 2)?,
+        read_column_height(rows(), 3)?,
+        read_column_height(rows(), 4)?,
+    );
+
+    Ok(Key { notch_heights })
+}
+
+fn do_key_lock_pair_fit(lock: &Lock, key: &Key) -> bool {
+    (lock.pin_heights.0 + key.notch_heights.0 <= 5)
+        && (lock.pin_heights.1 + key.notch_heights.1 <= 5)
+        && (lock.pin_heights.2 + key.notch_heights.2 <= 5)
+        && (lock.pin_heights.3 + key.notch_heights.3 <= 5)
+        && (lock.pin_heights.4 + key.notch_heights.4 <= 5)
+}
@@ -0,0 +1,181 @@
+//! Generates one `#[test]` per (year, day, input, part) from the `answers.toml` file in each year
+//! dir. Every input file found next to a day also gets tests, so an input without an entry in the
+//! answers file still shows up (as an unknown answer) instead of being silently skipped.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+const YEARS: [u32; 2] = [2020, 2024];
+
+#[derive(Default)]
+struct InputAnswers {
+    path: Option<PathBuf>,
+    args: Vec<String>,
+    parts: [Option<String>; 2],
+}
+
+type DayInputs = BTreeMap<String, InputAnswers>;
+
+fn main() {
+    let repo_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../..");
+
+    let mut tests = String::new();
+    for year in YEARS {
+        let year_dir = repo_root.join(year.to_string());
+        let answers_file = year_dir.join("answers.toml");
+        println!("cargo:rerun-if-changed={}", answers_file.display());
+
+        let mut days = find_inputs(&year_dir);
+        if answers_file.is_file() {
+            read_answers(&answers_file, &mut days);
+        }
+
+        for (day, inputs) in &days {
+            for (input_name, answers) in inputs {
+                let path = answers.path.as_ref().map(|p| p.display().to_string());
+                for (part_idx, answer) in answers.parts.iter().enumerate() {
+                    write_test(
+                        &mut tests,
+                        year,
+                        *day,
+                        input_name,
+                        part_idx as u8 + 1,
+                        path.as_deref(),
+                        &answers.args,
+                        answer.as_deref(),
+                    );
+                }
+            }
+        }
+    }
+
+    let out_file = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("answer_tests.rs");
+    std::fs::write(out_file, tests).unwrap();
+}
+
+fn is_input_file(file_name: &str) -> bool {
+    file_name.ends_with(".txt") && (file_name == "input.txt" || file_name.contains("_input"))
+}
+
+/// Finds the input files of every `dayNN` dir in `year_dir`, checking both the dir itself and its
+/// `src` dir (where the 2020 inputs live).
+fn find_inputs(year_dir: &Path) -> BTreeMap<u32, DayInputs> {
+    let mut days = BTreeMap::new();
+
+    let day_dirs = match std::fs::read_dir(year_dir) {
+        Ok(entries) => entries,
+        Err(_) => return days,
+    };
+    for day_dir in day_dirs.flatten() {
+        let dir_name = day_dir.file_name().to_string_lossy().to_string();
+        let day: u32 = match dir_name.strip_prefix("day").map(str::parse) {
+            Some(Ok(day)) => day,
+            _ => continue,
+        };
+        println!("cargo:rerun-if-changed={}", day_dir.path().display());
+
+        let inputs: &mut DayInputs = days.entry(day).or_default();
+        for dir in [day_dir.path(), day_dir.path().join("src")] {
+            let files = match std::fs::read_dir(&dir) {
+                Ok(files) => files,
+                Err(_) => continue,
+            };
+            for file in files.flatten() {
+                let file_name = file.file_name().to_string_lossy().to_string();
+                if is_input_file(&file_name) {
+                    inputs.entry(file_name).or_default().path = Some(file.path());
+                }
+            }
+        }
+    }
+
+    days
+}
+
+/// Merges the answers file into the discovered inputs. Tables look like:
+///
+/// ```toml
+/// [day09."simple_input.txt"]
+/// args = ["preamble=5"]
+/// part1 = "127"
+/// part2 = "unknown"
+/// ```
+fn read_answers(answers_file: &Path, days: &mut BTreeMap<u32, DayInputs>) {
+    let answers_str = std::fs::read_to_string(answers_file).unwrap();
+    let answers: toml::Table = answers_str
+        .parse()
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {}", answers_file.display(), e));
+
+    for (day_key, inputs) in &answers {
+        let day: u32 = day_key
+            .strip_prefix("day")
+            .and_then(|d| d.parse().ok())
+            .unwrap_or_else(|| panic!("Bad day key '{}' in {}", day_key, answers_file.display()));
+        let inputs = inputs
+            .as_table()
+            .unwrap_or_else(|| panic!("[{}] should be a table of inputs", day_key));
+
+        for (input_name, entry) in inputs {
+            let entry = entry
+                .as_table()
+                .unwrap_or_else(|| panic!("[{}.\"{}\"] should be a table", day_key, input_name));
+            let input_answers = days
+                .entry(day)
+                .or_default()
+                .entry(input_name.clone())
+                .or_default();
+
+            if let Some(args) = entry.get("args").and_then(|a| a.as_array()) {
+                input_answers.args = args
+                    .iter()
+                    .map(|a| match a {
+                        toml::Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    })
+                    .collect();
+            }
+            for (part_idx, part_key) in ["part1", "part2"].iter().enumerate() {
+                input_answers.parts[part_idx] = match entry.get(*part_key) {
+                    Some(toml::Value::String(s)) if s == "unknown" => None,
+                    Some(toml::Value::String(s)) => Some(s.clone()),
+                    Some(other) => Some(other.to_string()),
+                    None => None,
+                };
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn write_test(
+    tests: &mut String,
+    year: u32,
+    day: u32,
+    input_name: &str,
+    part: u8,
+    path: Option<&str>,
+    args: &[String],
+    answer: Option<&str>,
+) {
+    let input_ident: String = input_name
+        .trim_end_matches(".txt")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    writeln!(tests, "#[test]").unwrap();
+    writeln!(
+        tests,
+        "fn y{}_day{:02}_{}_pt{}() {{",
+        year, day, input_ident, part
+    )
+    .unwrap();
+    writeln!(
+        tests,
+        "    crate::answers::check(crate::answers::AnswerCase {{ year: {}, day: {}, input_name: {:?}, input_path: {:?}, part: {}, args: &{:?}, expected: {:?} }});",
+        year, day, input_name, path, part, args, answer
+    )
+    .unwrap();
+    writeln!(tests, "}}\n").unwrap();
+}
@@ -0,0 +1,86 @@
+//! Regression checks of every day against the expected answers in `<year>/answers.toml`. The
+//! tests themselves are generated by `build.rs`, one per (year, day, input, part).
+
+use crate::days;
+
+pub struct AnswerCase {
+    pub year: u32,
+    pub day: u32,
+    pub input_name: &'static str,
+    pub input_path: Option<&'static str>,
+    pub part: u8,
+    pub args: &'static [&'static str],
+    pub expected: Option<&'static str>,
+}
+
+/// Runs the case and panics with a diff if the answer doesn't match. Cases without a known answer
+/// (or without an input file/solution to run) are only reported as unknown.
+pub fn check(case: AnswerCase) {
+    let name = format!(
+        "{} day {:02} pt {} ({})",
+        case.year, case.day, case.part, case.input_name
+    );
+
+    let expected = match case.expected {
+        Some(expected) => expected,
+        None => {
+            println!("{}: answer unknown", name);
+            return;
+        }
+    };
+    let input_path = case
+        .input_path
+        .unwrap_or_else(|| panic!("{}: input file is missing", name));
+    let day = days::find_day(case.year, case.day)
+        .unwrap_or_else(|| panic!("{}: no solution for this day", name));
+
+    let args: Vec<String> = case.args.iter().map(|a| a.to_string()).collect();
+    let actual = (day.solve)(input_path, case.part, &args)
+        .unwrap_or_else(|e| panic!("{}: failed! {}", name, e));
+
+    if actual != expected {
+        panic!(
+            "{}: answer mismatch\n{}",
+            name,
+            diff_lines(expected, &actual)
+        );
+    }
+}
+
+/// A line-by-line diff, with `-` lines from `expected` and `+` lines from `actual`.
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    let mut diff = String::new();
+    for idx in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(idx), actual_lines.get(idx)) {
+            (Some(e), Some(a)) if e == a => diff.push_str(&format!("  {}\n", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    diff.push_str(&format!("- {}\n", e));
+                }
+                if let Some(a) = a {
+                    diff.push_str(&format!("+ {}\n", a));
+                }
+            }
+        }
+    }
+
+    diff
+}
+
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(
+            super::diff_lines("1\n2\n3", "1\n5\n3\n4"),
+            "  1\n- 2\n+ 5\n  3\n+ 4\n"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::days::Day;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// One benchmark of a day, as stored in the history file. Times are medians over `runs` runs.
+#[derive(Serialize, Deserialize)]
+pub struct BenchRecord {
+    pub timestamp: u64,
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+    pub args: Vec<String>,
+    pub runs: usize,
+    pub parse_ns: u64,
+    pub part1_ns: Option<u64>,
+    pub part2_ns: Option<u64>,
+}
+
+impl BenchRecord {
+    fn is_same_bench(&self, other: &BenchRecord) -> bool {
+        self.year == other.year
+            && self.day == other.day
+            && self.input == other.input
+            && self.args == other.args
+    }
+}
+
+pub struct BenchConfig<'a> {
+    pub input_file: &'a str,
+    pub parts: &'a [u8],
+    pub args: &'a [String],
+    pub runs: usize,
+    pub history_file: &'a Path,
+}
+
+fn median(mut times: Vec<Duration>) -> Option<Duration> {
+    times.sort();
+    times.get(times.len() / 2).copied()
+}
+
+/// Runs `day` once with its stdout thrown away, so the times are of the solving and not of
+/// printing (some days print the grid every generation).
+fn time_quietly(day: &Day, config: &BenchConfig) -> Result<solution::RunTimes, solution::Error> {
+    let mut stdout = std::io::stdout();
+    stdout.flush().map_err(|e| e.to_string())?;
+    let gag =
+        gag::Gag::stdout().map_err(|e| format!("Failed to silence the day's output: {}", e))?;
+
+    let run_times = (day.bench)(config.input_file, config.parts, config.args);
+
+    // anything still buffered has to go before stdout is put back
+    stdout.flush().map_err(|e| e.to_string())?;
+    drop(gag);
+    run_times
+}
+
+/// Times `day` `config.runs` times, prints the medians (compared to the last matching run in the
+/// history file, if any) and appends them to the history file.
+pub fn run_bench(day: &Day, config: &BenchConfig) -> Result<(), solution::Error> {
+    if cfg!(debug_assertions) {
+        return Err(
+            "Not benchmarking a debug build, its times would be misleading; run with --release"
+                .into(),
+        );
+    }
+    if config.runs == 0 {
+        return Err("Need at least 1 run to benchmark".into());
+    }
+
+    let mut parse_times = vec![];
+    let mut part1_times = vec![];
+    let mut part2_times = vec![];
+    for _ in 0..config.runs {
+        let run_times = time_quietly(day, config)?;
+        parse_times.push(run_times.parse);
+        part1_times.extend(run_times.part1);
+        part2_times.extend(run_times.part2);
+    }
+
+    let input_name = Path::new(config.input_file)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| config.input_file.to_string());
+    let record = BenchRecord {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        year: day.year,
+        day: day.day,
+        input: input_name,
+        args: config.args.to_vec(),
+        runs: config.runs,
+        parse_ns: median(parse_times).unwrap_or_default().as_nanos() as u64,
+        part1_ns: median(part1_times).map(|d| d.as_nanos() as u64),
+        part2_ns: median(part2_times).map(|d| d.as_nanos() as u64),
+    };
+
+    let mut history = read_history(config.history_file)?;
+    let previous = history.iter().rev().find(|r| r.is_same_bench(&record));
+
+    println!(
+        "\n{} day {:02} ({}), median of {} runs:",
+        record.year, record.day, record.input, record.runs
+    );
+    print_stage("parse", Some(record.parse_ns), previous.map(|p| p.parse_ns));
+    print_stage("pt 1", record.part1_ns, previous.and_then(|p| p.part1_ns));
+    print_stage("pt 2", record.part2_ns, previous.and_then(|p| p.part2_ns));
+
+    history.push(record);
+    Ok(write_history(config.history_file, &history)?)
+}
+
+fn print_stage(name: &str, time_ns: Option<u64>, previous_ns: Option<u64>) {
+    let time_ns = match time_ns {
+        Some(time_ns) => time_ns,
+        None => return,
+    };
+
+    let time = Duration::from_nanos(time_ns);
+    match previous_ns {
+        Some(previous_ns) if previous_ns > 0 => {
+            let change = (time_ns as f64 - previous_ns as f64) / previous_ns as f64 * 100.0;
+            println!(
+                "  {:<6} {:>12.3?}  (was {:.3?}, {:+.1}%)",
+                name,
+                time,
+                Duration::from_nanos(previous_ns),
+                change
+            );
+        }
+        _ => println!("  {:<6} {:>12.3?}", name, time),
+    }
+}
+
+fn read_history(history_file: &Path) -> Result<Vec<BenchRecord>, String> {
+    if !history_file.is_file() {
+        return Ok(vec![]);
+    }
+
+    let history_str = std::fs::read_to_string(history_file)
+        .map_err(|e| format!("Failed to read {}: {}", history_file.display(), e))?;
+    serde_json::from_str(&history_str)
+        .map_err(|e| format!("Failed to parse {}: {}", history_file.display(), e))
+}
+
+fn write_history(history_file: &Path, history: &[BenchRecord]) -> Result<(), String> {
+    let history_str = serde_json::to_string_pretty(history).map_err(|e| e.to_string())?;
+    std::fs::write(history_file, history_str + "\n")
+        .map_err(|e| format!("Failed to write {}: {}", history_file.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    #[test]
+    fn median_of_unsorted_times() {
+        let times = [5, 1, 3]
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect();
+        assert_eq!(super::median(times), Some(Duration::from_millis(3)));
+        assert_eq!(super::median(vec![]), None);
+    }
+}
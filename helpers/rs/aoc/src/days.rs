@@ -0,0 +1,68 @@
+pub type SolveFn = fn(&str, u8, &[String]) -> Result<String, solution::Error>;
+pub type BenchFn = fn(&str, &[u8], &[String]) -> Result<solution::RunTimes, solution::Error>;
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+}
+
+macro_rules! day {
+    ($year:expr, $day:expr, $krate:ident :: $solution:ident) => {
+        Day {
+            year: $year,
+            day: $day,
+            solve: solution::solve_part::<$krate::$solution>,
+            bench: solution::time_run::<$krate::$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(2020, 1, day01_2020::Day01),
+    day!(2020, 2, day02_2020::Day02),
+    day!(2020, 3, day03_2020::Day03),
+    day!(2020, 4, day04_2020::Day04),
+    day!(2020, 5, day05_2020::Day05),
+    day!(2020, 6, day06_2020::Day06),
+    day!(2020, 7, day07_2020::Day07),
+    day!(2020, 8, day08_2020::Day08),
+    day!(2020, 9, day09_2020::Day09),
+    day!(2020, 10, day10_2020::Day10),
+    day!(2020, 11, day11_2020::Day11),
+    day!(2020, 12, day12_2020::Day12),
+    day!(2020, 13, day13_2020::Day13),
+    day!(2020, 14, day14_2020::Day14),
+    day!(2020, 15, day15_2020::Day15),
+    day!(2020, 16, day16_2020::Day16),
+    day!(2020, 17, day17_2020::Day17),
+    day!(2020, 18, day18_2020::Day18),
+    day!(2024, 1, day01_2024::Day01),
+    day!(2024, 2, day02_2024::Day02),
+    day!(2024, 3, day03_2024::Day03),
+    day!(2024, 4, day04_2024::Day04),
+    day!(2024, 5, day05_2024::Day05),
+    day!(2024, 6, day06_2024::Day06),
+    day!(2024, 7, day07_2024::Day07),
+    day!(2024, 8, day08_2024::Day08),
+    day!(2024, 9, day09_2024::Day09),
+    day!(2024, 10, day10_2024::Day10),
+    day!(2024, 11, day11_2024::Day11),
+    day!(2024, 12, day12_2024::Day12),
+    day!(2024, 13, day13_2024::Day13),
+    day!(2024, 14, day14_2024::Day14),
+    day!(2024, 15, day15_2024::Day15),
+    day!(2024, 16, day16_2024::Day16),
+    day!(2024, 17, day17_2024::Day17),
+    day!(2024, 18, day18_2024::Day18),
+    day!(2024, 19, day19_2024::Day19),
+    day!(2024, 22, day22_2024::Day22),
+    day!(2024, 23, day23_2024::Day23),
+    day!(2024, 24, day24_2024::Day24),
+    day!(2024, 25, day25_2024::Day25),
+];
+
+pub fn find_day(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
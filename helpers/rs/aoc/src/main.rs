@@ -0,0 +1,262 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod bench;
+mod days;
+mod submit;
+
+#[cfg(test)]
+mod answers;
+
+const USAGE: &str = "USAGE: aoc <year> <day> [--part 1|2] [--input NAME] [--fetch] [--bench [--runs N] [--history FILE]] [day args...]
+       aoc submit <year> <day> --part 1|2 [--answer ANSWER] [--input NAME] [day args...]";
+const DEFAULT_BENCH_RUNS: usize = 10;
+
+struct RunConfig<'a> {
+    year: u32,
+    day: u32,
+    part: Option<u8>,
+    input_name: &'a str,
+    fetch: bool,
+    bench: bool,
+    bench_runs: usize,
+    bench_history: Option<&'a str>,
+    answer: Option<&'a str>,
+    day_args: Vec<String>,
+}
+
+fn parse_run_config(args: &[String]) -> Result<RunConfig<'_>, String> {
+    let year: u32 =
+        input_helpers::get_nth_parsed_arg(args, 0).map_err(|e| format!("{}\n{}", e, USAGE))?;
+    let day: u32 =
+        input_helpers::get_nth_parsed_arg(args, 1).map_err(|e| format!("{}\n{}", e, USAGE))?;
+
+    let mut part = None;
+    let mut input_name = "input";
+    let mut fetch = false;
+    let mut bench = false;
+    let mut bench_runs = DEFAULT_BENCH_RUNS;
+    let mut bench_history = None;
+    let mut answer = None;
+    let mut day_args = vec![];
+
+    let mut arg_itr = args[2..].iter();
+    while let Some(arg) = arg_itr.next() {
+        match arg.as_str() {
+            "-p" | "--part" => {
+                let part_str = arg_itr.next().ok_or(format!("Missing value for {}", arg))?;
+                match part_str.as_str() {
+                    "1" => part = Some(1),
+                    "2" => part = Some(2),
+                    _ => return Err(format!("Invalid part '{}'! Expected 1 or 2", part_str)),
+                }
+            }
+            "-i" | "--input" => {
+                input_name = arg_itr.next().ok_or(format!("Missing value for {}", arg))?;
+            }
+            "-f" | "--fetch" => fetch = true,
+            "-b" | "--bench" => bench = true,
+            "--runs" => {
+                let runs_str = arg_itr.next().ok_or(format!("Missing value for {}", arg))?;
+                bench_runs = runs_str
+                    .parse()
+                    .map_err(|_| format!("Invalid run count '{}'", runs_str))?;
+            }
+            "--history" => {
+                bench_history = Some(
+                    arg_itr
+                        .next()
+                        .ok_or(format!("Missing value for {}", arg))?
+                        .as_str(),
+                );
+            }
+            "-a" | "--answer" => {
+                answer = Some(
+                    arg_itr
+                        .next()
+                        .ok_or(format!("Missing value for {}", arg))?
+                        .as_str(),
+                );
+            }
+            _ => day_args.push(arg.clone()),
+        }
+    }
+
+    Ok(RunConfig {
+        year,
+        day,
+        part,
+        input_name,
+        fetch,
+        bench,
+        bench_runs,
+        bench_history,
+        answer,
+        day_args,
+    })
+}
+
+fn repo_root() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../../.."))
+}
+
+fn find_day(config: &RunConfig) -> Result<&'static days::Day, String> {
+    days::find_day(config.year, config.day).ok_or(format!(
+        "No solution for {} day {:02}",
+        config.year, config.day
+    ))
+}
+
+fn path_to_string(path: &Path) -> Result<String, String> {
+    path.to_str()
+        .map(String::from)
+        .ok_or(format!("Non utf-8 input path {}", path.display()))
+}
+
+fn find_input_files(config: &RunConfig, day_dir: &Path) -> Result<Vec<String>, String> {
+    input_helpers::find_input_files(day_dir, config.input_name)?
+        .iter()
+        .map(|input_file| path_to_string(input_file))
+        .collect()
+}
+
+fn run_input(
+    config: &RunConfig,
+    day: &days::Day,
+    input_file: &str,
+    parts: &[u8],
+) -> Result<(), solution::Error> {
+    if config.bench {
+        let history_file = match config.bench_history {
+            Some(history_file) => PathBuf::from(history_file),
+            None => repo_root()
+                .join(config.year.to_string())
+                .join("bench_history.json"),
+        };
+        return bench::run_bench(
+            day,
+            &bench::BenchConfig {
+                input_file,
+                parts,
+                args: &config.day_args,
+                runs: config.bench_runs,
+                history_file: &history_file,
+            },
+        );
+    }
+
+    for part in parts {
+        let answer = (day.solve)(input_file, *part, &config.day_args)?;
+        println!("Pt {}: {}", part, answer);
+    }
+
+    Ok(())
+}
+
+fn run_submit(config: &RunConfig, day_dir: &Path) -> Result<(), solution::Error> {
+    let part = config
+        .part
+        .ok_or("Which part is being submitted? Pass --part 1 or --part 2")?;
+
+    let answer = match config.answer {
+        Some(answer) => answer.to_string(),
+        None => {
+            let day = find_day(config)?;
+            let input_file = input_helpers::find_input_file(day_dir, config.input_name)?;
+            (day.solve)(&path_to_string(&input_file)?, part, &config.day_args)?
+        }
+    };
+
+    let guesses_file = repo_root()
+        .join(config.year.to_string())
+        .join("guesses.json");
+    let fetcher = input_helpers::fetch::Fetcher::from_env()?;
+    Ok(submit::submit_answer(
+        &fetcher,
+        config.year,
+        config.day,
+        part,
+        &answer,
+        &guesses_file,
+    )?)
+}
+
+fn run(args: &[String]) -> Result<(), solution::Error> {
+    let (is_submit, args) = match args.first().map(String::as_str) {
+        Some("submit") => (true, &args[1..]),
+        _ => (false, args),
+    };
+    let config = parse_run_config(args)?;
+
+    let day_dir = repo_root()
+        .join(config.year.to_string())
+        .join(format!("day{:02}", config.day));
+
+    if config.fetch {
+        let fetcher = input_helpers::fetch::Fetcher::from_env()?;
+        let input_file = fetcher.fetch_input_cached(
+            config.year,
+            config.day,
+            input_helpers::fetch::input_file_for_day(&day_dir),
+        )?;
+        println!(
+            "Input for {} day {:02} is at {}",
+            config.year,
+            config.day,
+            input_file.display()
+        );
+    }
+
+    if is_submit {
+        return run_submit(&config, &day_dir);
+    }
+
+    let day = find_day(&config)?;
+    let input_files = find_input_files(&config, &day_dir)?;
+
+    let parts: &[u8] = match config.part {
+        Some(1) => &[1],
+        Some(2) => &[2],
+        _ => &[1, 2],
+    };
+
+    // With several inputs (e.g. `--input samples`) one failing shouldn't hide the rest
+    let mut failed_inputs = vec![];
+    for input_file in &input_files {
+        println!("{} day {:02} ({})", config.year, config.day, input_file);
+        match run_input(&config, day, input_file, parts) {
+            Err(solution::Error::Failed(e)) if input_files.len() > 1 => {
+                println!("Err: {}", e);
+                failed_inputs.push(input_file.as_str());
+            }
+            result => result?,
+        }
+    }
+
+    if !failed_inputs.is_empty() {
+        return Err(format!(
+            "{} of {} inputs failed: {}",
+            failed_inputs.len(),
+            input_files.len(),
+            failed_inputs.join(", ")
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(solution::Error::Help(help)) => {
+            println!("{}", help);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            println!("Err: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use input_helpers::fetch::Fetcher;
+use input_helpers::submit::Verdict;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    AlreadySolved,
+    RateLimited,
+    Unrecognized,
+}
+
+impl Outcome {
+    /// Whether the site actually checked the answer, so sending it again would tell us nothing.
+    fn was_judged(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong
+        )
+    }
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let outcome_str = match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wrong => "wrong",
+            Outcome::AlreadySolved => "already solved",
+            Outcome::RateLimited => "rate limited",
+            Outcome::Unrecognized => "unrecognized",
+        };
+        write!(f, "{}", outcome_str)
+    }
+}
+
+/// One submitted answer, as stored in the guesses file.
+#[derive(Serialize, Deserialize)]
+pub struct Guess {
+    pub timestamp: u64,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_secs: Option<u64>,
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Checks `answer` against the earlier guesses for the same day and part, returning why it
+/// shouldn't be sent (if it shouldn't).
+pub fn check_guess(
+    guesses: &[Guess],
+    now: u64,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<(), String> {
+    let answer_value: Option<i64> = answer.parse().ok();
+    for guess in guesses.iter().filter(|g| g.day == day && g.part == part) {
+        match guess.outcome {
+            Outcome::Correct => {
+                return Err(format!("Already solved! The answer was {}", guess.answer));
+            }
+            Outcome::AlreadySolved => {
+                return Err(String::from(
+                    "Already solved! The site said so when an earlier answer was sent",
+                ));
+            }
+            _ => {}
+        }
+        if guess.answer == answer && guess.outcome.was_judged() {
+            return Err(format!(
+                "{} was already submitted and was {}",
+                answer, guess.outcome
+            ));
+        }
+        if let Some(wait_secs) = guess.wait_secs {
+            if now < guess.timestamp + wait_secs {
+                return Err(format!(
+                    "Rate limited; {}s left to wait",
+                    guess.timestamp + wait_secs - now
+                ));
+            }
+        }
+
+        let guess_value: Option<i64> = guess.answer.parse().ok();
+        match (answer_value, guess_value, guess.outcome) {
+            (Some(a), Some(g), Outcome::TooHigh) if a >= g => {
+                return Err(format!("{} can't be right; {} was too high", a, g));
+            }
+            (Some(a), Some(g), Outcome::TooLow) if a <= g => {
+                return Err(format!("{} can't be right; {} was too low", a, g));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Submits `answer` through `fetcher` unless the guesses file shows it can't be right, then
+/// records the verdict.
+pub fn submit_answer(
+    fetcher: &Fetcher,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+    guesses_file: &Path,
+) -> Result<(), String> {
+    let mut guesses = read_guesses(guesses_file)?;
+    check_guess(&guesses, now_secs(), day, part, answer)?;
+
+    println!(
+        "Submitting {} for {} day {:02} pt {}",
+        answer, year, day, part
+    );
+    let verdict = fetcher.submit_answer(year, day, part, answer)?;
+    println!("{}", verdict);
+
+    let (outcome, wait) = match verdict {
+        Verdict::Correct => (Outcome::Correct, None),
+        Verdict::TooHigh(cooldown) => (Outcome::TooHigh, cooldown),
+        Verdict::TooLow(cooldown) => (Outcome::TooLow, cooldown),
+        Verdict::Wrong(cooldown) => (Outcome::Wrong, cooldown),
+        Verdict::AlreadySolved => (Outcome::AlreadySolved, None),
+        Verdict::RateLimited(wait) => (Outcome::RateLimited, wait),
+        Verdict::Unrecognized(_) => (Outcome::Unrecognized, None),
+    };
+    guesses.push(Guess {
+        timestamp: now_secs(),
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome,
+        wait_secs: wait.map(|wait| wait.as_secs()),
+    });
+    write_guesses(guesses_file, &guesses)
+}
+
+fn read_guesses(guesses_file: &Path) -> Result<Vec<Guess>, String> {
+    if !guesses_file.is_file() {
+        return Ok(vec![]);
+    }
+
+    let guesses_str = std::fs::read_to_string(guesses_file)
+        .map_err(|e| format!("Failed to read {}: {}", guesses_file.display(), e))?;
+    serde_json::from_str(&guesses_str)
+        .map_err(|e| format!("Failed to parse {}: {}", guesses_file.display(), e))
+}
+
+fn write_guesses(guesses_file: &Path, guesses: &[Guess]) -> Result<(), String> {
+    let guesses_str = serde_json::to_string_pretty(guesses).map_err(|e| e.to_string())?;
+    std::fs::write(guesses_file, guesses_str + "\n")
+        .map_err(|e| format!("Failed to write {}: {}", guesses_file.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_guess, read_guesses, submit_answer, Guess, Outcome};
+    use input_helpers::fetch::Fetcher;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    /// A stand-in for the site that answers a single request with `page`, handing back the
+    /// request line and its form body.
+    fn serve_once(page: &'static str) -> (String, std::thread::JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                page.len(),
+                page
+            )
+            .unwrap();
+            (
+                request_line.trim().to_string(),
+                String::from_utf8(body).unwrap(),
+            )
+        });
+
+        (base_url, server)
+    }
+
+    fn guess(answer: &str, outcome: Outcome) -> Guess {
+        Guess {
+            timestamp: 100,
+            day: 3,
+            part: 1,
+            answer: answer.to_string(),
+            outcome,
+            wait_secs: None,
+        }
+    }
+
+    #[test]
+    fn rejects_known_bad_guesses() {
+        let guesses = vec![
+            guess("50", Outcome::TooHigh),
+            guess("10", Outcome::TooLow),
+            guess("abc", Outcome::Wrong),
+            guess("30", Outcome::RateLimited),
+        ];
+
+        assert!(check_guess(&guesses, 200, 3, 1, "50").is_err());
+        assert!(check_guess(&guesses, 200, 3, 1, "60").is_err());
+        assert!(check_guess(&guesses, 200, 3, 1, "5").is_err());
+        assert!(check_guess(&guesses, 200, 3, 1, "abc").is_err());
+        assert!(check_guess(&guesses, 200, 3, 1, "30").is_ok());
+        assert!(check_guess(&guesses, 200, 3, 2, "50").is_ok());
+    }
+
+    #[test]
+    fn stops_once_solved() {
+        for outcome in [Outcome::Correct, Outcome::AlreadySolved] {
+            let guesses = vec![guess("42", outcome)];
+            assert!(check_guess(&guesses, 200, 3, 1, "43").is_err());
+            assert!(check_guess(&guesses, 200, 3, 2, "43").is_ok());
+        }
+    }
+
+    #[test]
+    fn waits_out_rate_limit() {
+        let mut rate_limited = guess("30", Outcome::RateLimited);
+        rate_limited.wait_secs = Some(60);
+        let guesses = vec![rate_limited];
+
+        assert!(check_guess(&guesses, 120, 3, 1, "31").is_err());
+        assert!(check_guess(&guesses, 160, 3, 1, "31").is_ok());
+    }
+
+    #[test]
+    fn submits_and_records_the_verdict() {
+        let (base_url, server) = serve_once(
+            "<html><main><article><p>That's not the right answer; your answer is too high.  \
+             Please wait one minute before trying again.</p></article></main></html>",
+        );
+        let fetcher = Fetcher::new(&base_url, "abc123");
+        let guesses_file =
+            std::env::temp_dir().join(format!("aoc_submit_guesses_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&guesses_file);
+
+        submit_answer(&fetcher, 2024, 7, 1, "500", &guesses_file).unwrap();
+        let (request_line, form) = server.join().unwrap();
+        assert_eq!(request_line, "POST /2024/day/7/answer HTTP/1.1");
+        assert_eq!(form, "level=1&answer=500");
+
+        let guesses = read_guesses(&guesses_file).unwrap();
+        assert_eq!(guesses.len(), 1);
+        assert_eq!((guesses[0].day, guesses[0].part), (7, 1));
+        assert_eq!(guesses[0].answer, "500");
+        assert_eq!(guesses[0].outcome, Outcome::TooHigh);
+        assert_eq!(guesses[0].wait_secs, Some(60));
+
+        // The stand-in is gone, so this is only refused (rather than failing to connect) if the
+        // guesses file stops it before any request is made.
+        let refused = submit_answer(&fetcher, 2024, 7, 1, "500", &guesses_file).unwrap_err();
+        assert_eq!(refused, "500 was already submitted and was too high");
+        assert_eq!(read_guesses(&guesses_file).unwrap().len(), 1);
+
+        std::fs::remove_file(&guesses_file).unwrap();
+    }
+}
@@ -0,0 +1,272 @@
+//! Cellular automata (like Conway's Game of Life) over any of the grid types: each generation,
+//! every cell's next state comes from a rule over the cell and its neighbors, all read from the
+//! generation before.
+
+use crate::{Coord, Grid, GridPos, SparseGrid};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// A grid an [`Automaton`] can run on.
+pub trait World: Clone {
+    type Pos: Copy;
+    type Cell: Copy + PartialEq;
+
+    /// Every position whose cell might change in the next generation.
+    fn positions_to_update(&self) -> Vec<Self::Pos>;
+
+    fn cell(&self, pos: Self::Pos) -> Self::Cell;
+
+    fn set_cell(&mut self, pos: Self::Pos, cell: Self::Cell);
+}
+
+impl<T> World for Grid<T>
+where
+    T: Clone + Copy + PartialEq,
+{
+    type Pos = GridPos;
+    type Cell = T;
+
+    fn positions_to_update(&self) -> Vec<GridPos> {
+        (0..self.cells.len())
+            .map(|idx| Grid::<T>::cell_pos_from_idx(self.width, self.height, idx))
+            .collect()
+    }
+
+    fn cell(&self, pos: GridPos) -> T {
+        self.get_cell(pos.row, pos.col)
+    }
+
+    fn set_cell(&mut self, pos: GridPos, cell: T) {
+        *self.get_cell_mut(pos.row, pos.col) = cell;
+    }
+}
+
+/// Only the cells that aren't the default value, and their neighbors, are updated. That assumes
+/// the rule leaves a default cell with only default neighbors alone (e.g. a dead cell with no
+/// live neighbors stays dead), or the grid would fill up the whole infinite plane.
+impl<T, P> World for SparseGrid<T, P>
+where
+    T: Clone + Copy + PartialEq,
+    P: Coord,
+{
+    type Pos = P;
+    type Cell = T;
+
+    fn positions_to_update(&self) -> Vec<P> {
+        let mut positions = HashSet::new();
+        for (pos, _) in self.iter() {
+            positions.insert(pos);
+            positions.extend(self.neighbors(pos));
+        }
+        positions.into_iter().collect()
+    }
+
+    fn cell(&self, pos: P) -> T {
+        self.get(pos)
+    }
+
+    fn set_cell(&mut self, pos: P, cell: T) {
+        self.set(pos, cell)
+    }
+}
+
+/// Where [`Automaton::run_until_repeat`] found the world going round in circles.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct Cycle {
+    /// The first generation of the loop.
+    pub start: usize,
+    /// How many generations it takes to come back around; 1 for a world that stopped changing.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The generation from the first time round the loop that `generation` looks the same as.
+    pub fn equivalent_generation(&self, generation: usize) -> usize {
+        if generation < self.start {
+            generation
+        } else {
+            self.start + (generation - self.start) % self.length
+        }
+    }
+}
+
+/// Runs a cellular automaton over a [`World`], double buffered so every cell of a generation is
+/// worked out from the same previous generation. The back buffer isn't copied each generation;
+/// it's caught up by writing in only the cells the last generation changed.
+///
+/// Each step takes the two halves of the rules: `neighbors(world, pos, neighbor_positions)` adds
+/// the positions that count as the neighbors of `pos` (adjacent, orthogonal, the first seat in
+/// sight, ...) to `neighbor_positions`, and `rule(cell, neighbor_cells)` gives the cell's next
+/// state. Filling in a list rather than returning one lets `neighbors` lean on the world it's
+/// given, e.g. `|world, pos, neighbors| neighbors.extend(world.neighbors(pos))`.
+pub struct Automaton<W>
+where
+    W: World,
+{
+    world: W,
+    /// The generation before `world`, apart from the cells in `changes`.
+    buffer: W,
+    /// The cells the last step changed, which `buffer` doesn't have yet.
+    changes: Vec<(W::Pos, W::Cell)>,
+    /// Reused by each step for the neighbors of the cell it's on.
+    neighbor_positions: Vec<W::Pos>,
+    generation: usize,
+}
+
+impl<W> Automaton<W>
+where
+    W: World,
+{
+    pub fn new(world: W) -> Automaton<W> {
+        Automaton {
+            buffer: world.clone(),
+            world,
+            changes: vec![],
+            neighbor_positions: vec![],
+            generation: 0,
+        }
+    }
+
+    pub fn world(&self) -> &W {
+        &self.world
+    }
+
+    pub fn into_world(self) -> W {
+        self.world
+    }
+
+    /// How many generations have been run.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Runs one generation. Returns whether any cell changed.
+    pub fn step<N, R>(&mut self, mut neighbors: N, mut rule: R) -> bool
+    where
+        N: FnMut(&W, W::Pos, &mut Vec<W::Pos>),
+        R: FnMut(W::Cell, &[W::Cell]) -> W::Cell,
+    {
+        for (pos, cell) in self.changes.drain(..) {
+            self.buffer.set_cell(pos, cell);
+        }
+
+        let mut neighbor_cells = vec![];
+        for pos in self.world.positions_to_update() {
+            self.neighbor_positions.clear();
+            neighbors(&self.world, pos, &mut self.neighbor_positions);
+            neighbor_cells.clear();
+            neighbor_cells.extend(
+                self.neighbor_positions
+                    .iter()
+                    .map(|neighbor_pos| self.world.cell(*neighbor_pos)),
+            );
+
+            let cell = self.world.cell(pos);
+            let next_cell = rule(cell, &neighbor_cells);
+            if next_cell != cell {
+                self.buffer.set_cell(pos, next_cell);
+                self.changes.push((pos, next_cell));
+            }
+        }
+
+        std::mem::swap(&mut self.world, &mut self.buffer);
+        self.generation += 1;
+        !self.changes.is_empty()
+    }
+
+    pub fn run<N, R>(&mut self, generations: usize, mut neighbors: N, mut rule: R)
+    where
+        N: FnMut(&W, W::Pos, &mut Vec<W::Pos>),
+        R: FnMut(W::Cell, &[W::Cell]) -> W::Cell,
+    {
+        for _ in 0..generations {
+            self.step(&mut neighbors, &mut rule);
+        }
+    }
+
+    /// Runs until a generation changes nothing. Returns how many generations changed something
+    /// before that. Never returns if the world keeps changing.
+    pub fn run_until_stable<N, R>(&mut self, mut neighbors: N, mut rule: R) -> usize
+    where
+        N: FnMut(&W, W::Pos, &mut Vec<W::Pos>),
+        R: FnMut(W::Cell, &[W::Cell]) -> W::Cell,
+    {
+        let mut changed_generations = 0;
+        while self.step(&mut neighbors, &mut rule) {
+            changed_generations += 1;
+        }
+        changed_generations
+    }
+
+    /// Runs until the world looks like it did in an earlier generation, remembering every
+    /// generation along the way. Never returns if the world never repeats.
+    pub fn run_until_repeat<N, R>(&mut self, mut neighbors: N, mut rule: R) -> Cycle
+    where
+        W: Hash + Eq,
+        N: FnMut(&W, W::Pos, &mut Vec<W::Pos>),
+        R: FnMut(W::Cell, &[W::Cell]) -> W::Cell,
+    {
+        let mut seen = HashMap::new();
+        seen.insert(self.world.clone(), self.generation);
+        loop {
+            self.step(&mut neighbors, &mut rule);
+            if let Some(first_seen) = seen.get(&self.world) {
+                return Cycle {
+                    start: *first_seen,
+                    length: self.generation - first_seen,
+                };
+            }
+            seen.insert(self.world.clone(), self.generation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Automaton, Cycle};
+    use crate::{Grid, GridPos, PointN, SparseGrid};
+
+    fn life_rule(alive: bool, neighbors: &[bool]) -> bool {
+        let alive_neighbors = neighbors.iter().filter(|neighbor| **neighbor).count();
+        alive_neighbors == 3 || (alive && alive_neighbors == 2)
+    }
+
+    #[test]
+    fn automata_settle_and_cycle() {
+        let parse_life = |text| Grid::parse(text, |c, _pos| Ok::<_, String>(c == '#')).unwrap();
+        let adjacent = |grid: &Grid<bool>, pos, neighbors: &mut Vec<GridPos>| {
+            neighbors.extend(grid.neighbors8(pos))
+        };
+
+        let mut blinker = Automaton::new(parse_life("...\n###\n..."));
+        assert!(blinker.step(adjacent, life_rule));
+        assert_eq!(blinker.world().cells, parse_life(".#.\n.#.\n.#.").cells);
+        let cycle = blinker.run_until_repeat(adjacent, life_rule);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 1,
+                length: 2
+            }
+        );
+        assert_eq!(cycle.equivalent_generation(1001), 1);
+
+        // a block is still, and the cells around it don't come to life
+        let mut block = Automaton::new(parse_life("....\n.##.\n.##.\n...."));
+        assert_eq!(block.run_until_stable(adjacent, life_rule), 0);
+        assert_eq!(block.generation(), 1);
+
+        // 3D life on an unbounded grid; a line of three grows rather than blinking
+        let mut sparse = SparseGrid::new(false);
+        for x in -1..=1 {
+            sparse.set(PointN::new([x, 0, 0]), true);
+        }
+        let mut sparse = Automaton::new(sparse);
+        let all_adjacent =
+            |grid: &SparseGrid<bool, PointN<3>>, pos, neighbors: &mut Vec<PointN<3>>| {
+                neighbors.extend(grid.neighbors(pos))
+            };
+        sparse.run(2, all_adjacent, life_rule);
+        assert_eq!(sparse.world().len(), 16);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::GridPos;
+use std::ops::{Add, AddAssign};
+
+/// One of the four orthogonal directions, with north being up (row - 1).
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Dir4 {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir4 {
+    /// Clockwise from north.
+    pub const ALL: [Dir4; 4] = [Dir4::N, Dir4::E, Dir4::S, Dir4::W];
+
+    pub fn turn_cw(self) -> Dir4 {
+        match self {
+            Dir4::N => Dir4::E,
+            Dir4::E => Dir4::S,
+            Dir4::S => Dir4::W,
+            Dir4::W => Dir4::N,
+        }
+    }
+
+    pub fn turn_ccw(self) -> Dir4 {
+        match self {
+            Dir4::N => Dir4::W,
+            Dir4::E => Dir4::N,
+            Dir4::S => Dir4::E,
+            Dir4::W => Dir4::S,
+        }
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        self.turn_cw().turn_cw()
+    }
+
+    /// The (row, col) step taken when moving in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        Dir8::from(self).offset()
+    }
+}
+
+/// One of the eight directions: the four orthogonal ones and the diagonals between them.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// Clockwise from north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    fn index(self) -> usize {
+        Dir8::ALL.iter().position(|d| *d == self).unwrap()
+    }
+
+    /// Turns an eighth of the way clockwise (e.g. N to NE).
+    pub fn turn_cw(self) -> Dir8 {
+        Dir8::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turns an eighth of the way counterclockwise (e.g. N to NW).
+    pub fn turn_ccw(self) -> Dir8 {
+        Dir8::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        Dir8::ALL[(self.index() + 4) % 8]
+    }
+
+    /// The (row, col) step taken when moving in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Dir8::N => (-1, 0),
+            Dir8::NE => (-1, 1),
+            Dir8::E => (0, 1),
+            Dir8::SE => (1, 1),
+            Dir8::S => (1, 0),
+            Dir8::SW => (1, -1),
+            Dir8::W => (0, -1),
+            Dir8::NW => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Dir8 {
+        match dir {
+            Dir4::N => Dir8::N,
+            Dir4::E => Dir8::E,
+            Dir4::S => Dir8::S,
+            Dir4::W => Dir8::W,
+        }
+    }
+}
+
+impl Add<Dir8> for GridPos {
+    type Output = GridPos;
+
+    /// The neighboring position in direction `dir`, which may be out of bounds.
+    fn add(self, dir: Dir8) -> GridPos {
+        let (row_offset, col_offset) = dir.offset();
+        GridPos {
+            row: self.row + row_offset,
+            col: self.col + col_offset,
+        }
+    }
+}
+
+impl Add<Dir4> for GridPos {
+    type Output = GridPos;
+
+    fn add(self, dir: Dir4) -> GridPos {
+        self + Dir8::from(dir)
+    }
+}
+
+impl AddAssign<Dir8> for GridPos {
+    fn add_assign(&mut self, dir: Dir8) {
+        *self = *self + dir;
+    }
+}
+
+impl AddAssign<Dir4> for GridPos {
+    fn add_assign(&mut self, dir: Dir4) {
+        *self = *self + dir;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dir4, Dir8};
+    use crate::GridPos;
+
+    #[test]
+    fn dirs_turn_and_step() {
+        assert_eq!(Dir4::N.turn_cw(), Dir4::E);
+        assert_eq!(Dir4::N.turn_ccw(), Dir4::W);
+        assert_eq!(Dir4::E.opposite(), Dir4::W);
+        assert_eq!(Dir8::N.turn_cw(), Dir8::NE);
+        assert_eq!(Dir8::N.turn_ccw(), Dir8::NW);
+        assert_eq!(Dir8::SW.opposite(), Dir8::NE);
+
+        let mut pos = GridPos { row: 2, col: 2 } + Dir4::N;
+        assert_eq!(pos, GridPos { row: 1, col: 2 });
+        pos += Dir8::SE;
+        assert_eq!(pos, GridPos { row: 2, col: 3 });
+    }
+}
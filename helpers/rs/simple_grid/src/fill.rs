@@ -0,0 +1,111 @@
+use crate::{Grid, GridPos};
+
+/// The connected regions of a grid, from [`Grid::components`].
+#[derive(Clone, Debug)]
+pub struct Components {
+    /// Which component each cell is in; an index into `cells`.
+    pub labels: Grid<usize>,
+    /// The cells of each component, starting from its first cell in reading order. Components are
+    /// numbered in reading order of their first cells too.
+    pub cells: Vec<Vec<GridPos>>,
+}
+
+impl<T> Grid<T>
+where
+    T: Clone + Copy,
+{
+    /// Every cell reachable from `start` by orthogonal steps that `can_step(from, to)` allows,
+    /// `start` first. Uses its own stack rather than recursing, so big regions are fine.
+    pub fn flood_fill<F>(&self, start: GridPos, can_step: F) -> Vec<GridPos>
+    where
+        F: FnMut(T, T) -> bool,
+    {
+        let mut visited = Grid::new(self.width, self.height, false);
+        self.fill_unvisited(start, &mut visited, can_step)
+    }
+
+    /// Splits the grid into regions of cells joined by orthogonal steps between cells for which
+    /// `same_region(a, b)` holds (e.g. `|a, b| a == b`). It should be symmetric.
+    pub fn components<F>(&self, mut same_region: F) -> Components
+    where
+        F: FnMut(T, T) -> bool,
+    {
+        let mut visited = Grid::new(self.width, self.height, false);
+        let mut labels = Grid::new(self.width, self.height, 0);
+        let mut cells = vec![];
+
+        for idx in 0..self.cells.len() {
+            if visited.cells[idx] {
+                continue;
+            }
+
+            let start = Grid::<T>::cell_pos_from_idx(self.width, self.height, idx);
+            let component = self.fill_unvisited(start, &mut visited, &mut same_region);
+            for pos in &component {
+                *labels.get_cell_mut(pos.row, pos.col) = cells.len();
+            }
+            cells.push(component);
+        }
+
+        Components { labels, cells }
+    }
+
+    fn fill_unvisited<F>(
+        &self,
+        start: GridPos,
+        visited: &mut Grid<bool>,
+        mut can_step: F,
+    ) -> Vec<GridPos>
+    where
+        F: FnMut(T, T) -> bool,
+    {
+        if visited.get(start) != Some(false) {
+            return vec![];
+        }
+        *visited.get_cell_mut(start.row, start.col) = true;
+
+        let mut filled = vec![start];
+        let mut to_check = vec![start];
+        while let Some(pos) = to_check.pop() {
+            let cell = self.get_cell(pos.row, pos.col);
+            for neighbor_pos in self.neighbors4(pos) {
+                let neighbor_visited = visited.get_cell_mut(neighbor_pos.row, neighbor_pos.col);
+                if !*neighbor_visited
+                    && can_step(cell, self.get_cell(neighbor_pos.row, neighbor_pos.col))
+                {
+                    *neighbor_visited = true;
+                    filled.push(neighbor_pos);
+                    to_check.push(neighbor_pos);
+                }
+            }
+        }
+
+        filled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, GridPos};
+
+    #[test]
+    fn fills_and_labels_regions() {
+        let grid = Grid::parse("aab\nbab\nbbb", |c, _pos| Ok::<_, String>(c)).unwrap();
+
+        let a_cells = grid.flood_fill(GridPos { row: 0, col: 0 }, |a, b| a == b);
+        assert_eq!(a_cells.len(), 3);
+        assert_eq!(a_cells[0], GridPos { row: 0, col: 0 });
+        let out_of_bounds = GridPos { row: 3, col: 0 };
+        assert!(grid.flood_fill(out_of_bounds, |_, _| true).is_empty());
+
+        let components = grid.components(|a, b| a == b);
+        assert_eq!(components.cells.len(), 2);
+        assert_eq!(components.labels.cells, [0, 0, 1, 1, 0, 1, 1, 1, 1]);
+        assert_eq!(components.cells[1][0], GridPos { row: 0, col: 2 });
+        assert_eq!(components.cells[1].len(), 6);
+
+        // filling a 1000x1000 region recursively would overflow the stack
+        let big = Grid::new(1000, 1000, ());
+        assert_eq!(big.components(|_, _| true).cells[0].len(), 1000 * 1000);
+    }
+}
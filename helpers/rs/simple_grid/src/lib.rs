@@ -1,3 +1,27 @@
+pub mod automaton;
+mod dir;
+mod fill;
+mod n_dim;
+mod parse;
+pub mod path;
+mod ray;
+mod region;
+pub mod search;
+mod sparse;
+mod sub_grid;
+mod transform;
+mod wrap;
+pub use dir::{Dir4, Dir8};
+pub use fill::Components;
+pub use n_dim::PointN;
+pub use parse::{GridLines, Markers};
+pub use ray::{lattice_points, lattice_step, Ray};
+pub use region::Region;
+pub use sparse::{Coord, SparseGrid};
+pub use sub_grid::SubGrid;
+pub use transform::Orientation;
+pub use wrap::{Wrap, WrappingBounds};
+
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
 pub struct GridPos {
     pub row: isize,
@@ -10,7 +34,7 @@ impl std::fmt::Display for GridPos {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Grid<T>
 where
     T: Clone + Copy,
@@ -24,6 +48,15 @@ impl<T> Grid<T>
 where
     T: Clone + Copy,
 {
+    /// A `width` x `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
     pub fn get_cell_idx(&self, row: isize, col: isize) -> usize {
         assert!(!self.is_pos_out_of_bounds(row, col));
 
@@ -39,6 +72,31 @@ where
         &mut self.cells[idx]
     }
 
+    /// The cell at `pos`, or `None` if it's out of bounds.
+    pub fn get(&self, pos: GridPos) -> Option<T> {
+        if self.is_pos_in_bounds(pos.row, pos.col) {
+            Some(self.get_cell(pos.row, pos.col))
+        } else {
+            None
+        }
+    }
+
+    /// The in-bounds orthogonal neighbors of `pos`, clockwise from north.
+    pub fn neighbors4(&self, pos: GridPos) -> impl Iterator<Item = GridPos> + '_ {
+        Dir4::ALL
+            .iter()
+            .map(move |dir| pos + *dir)
+            .filter(move |neighbor| self.is_pos_in_bounds(neighbor.row, neighbor.col))
+    }
+
+    /// The in-bounds neighbors of `pos` including diagonals, clockwise from north.
+    pub fn neighbors8(&self, pos: GridPos) -> impl Iterator<Item = GridPos> + '_ {
+        Dir8::ALL
+            .iter()
+            .map(move |dir| pos + *dir)
+            .filter(move |neighbor| self.is_pos_in_bounds(neighbor.row, neighbor.col))
+    }
+
     pub fn cell_pos_from_idx(width: usize, height: usize, idx: usize) -> GridPos {
         assert!(idx < (width * height));
         let col = (idx % width) as isize;
@@ -57,8 +115,19 @@ where
 
 #[cfg(test)]
 mod tests {
-    // FIXME: add tests...
+    use super::{Grid, GridPos};
+
     #[test]
-    fn it_works() {
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::new(3, 2, 0u8);
+        let corner = GridPos { row: 0, col: 0 };
+        assert_eq!(
+            grid.neighbors4(corner).collect::<Vec<_>>(),
+            [GridPos { row: 0, col: 1 }, GridPos { row: 1, col: 0 }]
+        );
+        assert_eq!(grid.neighbors8(corner).count(), 3);
+        assert_eq!(grid.neighbors8(GridPos { row: 1, col: 1 }).count(), 5);
+        assert_eq!(grid.get(GridPos { row: -1, col: 0 }), None);
+        assert_eq!(grid.get(GridPos { row: 1, col: 2 }), Some(0));
     }
 }
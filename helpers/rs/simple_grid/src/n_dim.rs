@@ -0,0 +1,103 @@
+use crate::Coord;
+use std::ops::Add;
+
+/// A position in `D` dimensions, e.g. `PointN<3>` for (x, y, z).
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct PointN<const D: usize> {
+    pub coords: [isize; D],
+}
+
+impl<const D: usize> PointN<D> {
+    pub const ORIGIN: PointN<D> = PointN { coords: [0; D] };
+
+    pub fn new(coords: [isize; D]) -> PointN<D> {
+        PointN { coords }
+    }
+
+    /// The 3^D - 1 steps to every neighboring point, diagonals included: each axis moves by -1, 0
+    /// or 1, but not all of them by 0.
+    pub fn neighbor_offsets() -> Vec<PointN<D>> {
+        let mut offsets = Vec::with_capacity(3usize.pow(D as u32) - 1);
+        // count in base 3, one digit per axis
+        for n in 0..3usize.pow(D as u32) {
+            let mut offset = PointN::ORIGIN;
+            let mut digits = n;
+            for coord in offset.coords.iter_mut() {
+                *coord = (digits % 3) as isize - 1;
+                digits /= 3;
+            }
+            if offset != PointN::ORIGIN {
+                offsets.push(offset);
+            }
+        }
+        offsets
+    }
+}
+
+impl<const D: usize> Add for PointN<D> {
+    type Output = PointN<D>;
+
+    fn add(self, other: PointN<D>) -> PointN<D> {
+        let mut sum = self;
+        for (coord, other_coord) in sum.coords.iter_mut().zip(other.coords.iter()) {
+            *coord += other_coord;
+        }
+        sum
+    }
+}
+
+impl<const D: usize> std::fmt::Display for PointN<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let coords: Vec<String> = self.coords.iter().map(|coord| coord.to_string()).collect();
+        write!(f, "({})", coords.join(", "))
+    }
+}
+
+impl<const D: usize> Coord for PointN<D> {
+    fn neighbor_offsets() -> Vec<PointN<D>> {
+        PointN::neighbor_offsets()
+    }
+
+    fn offset_by(self, offset: PointN<D>) -> PointN<D> {
+        self + offset
+    }
+
+    fn min_each(self, other: PointN<D>) -> PointN<D> {
+        let mut min = self;
+        for (coord, other_coord) in min.coords.iter_mut().zip(other.coords.iter()) {
+            *coord = (*coord).min(*other_coord);
+        }
+        min
+    }
+
+    fn max_each(self, other: PointN<D>) -> PointN<D> {
+        let mut max = self;
+        for (coord, other_coord) in max.coords.iter_mut().zip(other.coords.iter()) {
+            *coord = (*coord).max(*other_coord);
+        }
+        max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PointN;
+    use crate::SparseGrid;
+
+    #[test]
+    fn n_dim_points() {
+        assert_eq!(PointN::<1>::neighbor_offsets().len(), 2);
+        assert_eq!(PointN::<3>::neighbor_offsets().len(), 26);
+        assert_eq!(PointN::<5>::neighbor_offsets().len(), 242);
+        assert_eq!(PointN::new([1, -2, 3]).to_string(), "(1, -2, 3)");
+
+        let mut sparse = SparseGrid::new(false);
+        sparse.set(PointN::new([0, 0, 0, 0]), true);
+        sparse.set(PointN::new([-1, 2, 0, 5]), true);
+        assert_eq!(sparse.neighbors(PointN::ORIGIN).count(), 80);
+        assert_eq!(
+            sparse.bounding_box(),
+            Some((PointN::new([-1, 0, 0, 0]), PointN::new([0, 2, 0, 5])))
+        );
+    }
+}
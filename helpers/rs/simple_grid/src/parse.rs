@@ -0,0 +1,230 @@
+use crate::{Grid, GridPos};
+use input_helpers::{ParseError, Section};
+use std::fmt::Display;
+
+/// The rows a grid is parsed from, numbered so errors point at the right line of the input: all of
+/// some text, or one section of a sectioned input.
+pub struct GridLines<'a> {
+    /// 1-based line number of the first row.
+    pub first_line_number: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> From<&'a str> for GridLines<'a> {
+    /// Every line of `text`. Blank lines at the end (e.g. a trailing newline) aren't rows.
+    fn from(text: &'a str) -> GridLines<'a> {
+        let mut lines: Vec<&str> = text.lines().collect();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+
+        GridLines {
+            first_line_number: 1,
+            lines,
+        }
+    }
+}
+
+impl<'a> From<&'a String> for GridLines<'a> {
+    fn from(text: &'a String) -> GridLines<'a> {
+        GridLines::from(text.as_str())
+    }
+}
+
+impl<'a> From<Section<'a>> for GridLines<'a> {
+    fn from(section: Section<'a>) -> GridLines<'a> {
+        GridLines {
+            first_line_number: section.first_line_number,
+            lines: section.lines.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// Where the marker chars (e.g. `S`, `E` or `@`) were found by [`Grid::parse_with_markers`].
+#[derive(Clone, Debug, Default)]
+pub struct Markers {
+    found: Vec<(char, GridPos)>,
+}
+
+impl Markers {
+    /// Every position of `marker`, in reading order.
+    pub fn positions(&self, marker: char) -> Vec<GridPos> {
+        self.found
+            .iter()
+            .filter(|(found_marker, _)| *found_marker == marker)
+            .map(|(_, pos)| *pos)
+            .collect()
+    }
+
+    /// The position of a marker that has to appear exactly once.
+    pub fn only(&self, marker: char) -> Result<GridPos, String> {
+        match self.positions(marker).as_slice() {
+            [pos] => Ok(*pos),
+            [] => Err(format!("No '{}' in the grid", marker)),
+            positions => Err(format!(
+                "Expected one '{}' in the grid; found {} at {}",
+                marker,
+                positions.len(),
+                positions
+                    .iter()
+                    .map(|pos| pos.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+}
+
+fn char_columns(line: &str, char_idx: usize) -> std::ops::Range<usize> {
+    match line.char_indices().nth(char_idx) {
+        Some((start, c)) => start..start + c.len_utf8(),
+        None => line.len()..line.len(),
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone + Copy,
+{
+    /// Parses one cell per char, row by row. Every row has to be as wide as the first. When
+    /// `parse_cell` fails its error should say what was expected instead (e.g. "'.' or '#'"), and
+    /// the returned error points at the offending char.
+    ///
+    /// ```
+    /// use simple_grid::{Grid, GridPos};
+    ///
+    /// let grid = Grid::parse("#.\n.#\n", |c, _pos| match c {
+    ///     '.' => Ok(false),
+    ///     '#' => Ok(true),
+    ///     _ => Err("'.' or '#'"),
+    /// })
+    /// .unwrap();
+    /// assert_eq!((grid.width, grid.height), (2, 2));
+    /// assert!(grid.get_cell(1, 1));
+    ///
+    /// let ragged = Grid::parse("#.\n.#.", |_, _| Ok::<_, String>(true)).unwrap_err();
+    /// assert_eq!((ragged.line_number, ragged.offending_text()), (2, "."));
+    /// ```
+    pub fn parse<'a, L, E, F>(input: L, parse_cell: F) -> Result<Grid<T>, ParseError>
+    where
+        L: Into<GridLines<'a>>,
+        E: Display,
+        F: FnMut(char, GridPos) -> Result<T, E>,
+    {
+        Grid::parse_with_markers(input, &[], parse_cell).map(|(grid, _)| grid)
+    }
+
+    /// Like [`Grid::parse`], but also records where each of `markers` was found. Marker chars
+    /// still go through `parse_cell`, which says what's under them (e.g. `S` is an empty space).
+    pub fn parse_with_markers<'a, L, E, F>(
+        input: L,
+        markers: &[char],
+        mut parse_cell: F,
+    ) -> Result<(Grid<T>, Markers), ParseError>
+    where
+        L: Into<GridLines<'a>>,
+        E: Display,
+        F: FnMut(char, GridPos) -> Result<T, E>,
+    {
+        let GridLines {
+            first_line_number,
+            lines,
+        } = input.into();
+
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        let mut found_markers = Markers::default();
+
+        for (row, line) in lines.iter().enumerate() {
+            let line_number = first_line_number + row;
+            let row_width = line.chars().count();
+            if row_width != width {
+                return Err(ParseError::new(
+                    line_number,
+                    line,
+                    char_columns(line, width).start..line.len(),
+                    &format!("a row {} wide like the first row", width),
+                ));
+            }
+
+            for (col, c) in line.chars().enumerate() {
+                let pos = GridPos {
+                    row: row as isize,
+                    col: col as isize,
+                };
+                let cell = parse_cell(c, pos).map_err(|e| {
+                    ParseError::new(line_number, line, char_columns(line, col), &e.to_string())
+                })?;
+                if markers.contains(&c) {
+                    found_markers.found.push((c, pos));
+                }
+                cells.push(cell);
+            }
+        }
+
+        let grid = Grid {
+            width,
+            height: lines.len(),
+            cells,
+        };
+        Ok((grid, found_markers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Markers;
+    use crate::{Grid, GridPos};
+
+    fn parse_walls(text: &str) -> Result<(Grid<bool>, Markers), input_helpers::ParseError> {
+        Grid::parse_with_markers(text, &['S', 'E'], |c, _pos| match c {
+            '#' => Ok(true),
+            '.' | 'S' | 'E' => Ok(false),
+            _ => Err("'.', '#', 'S' or 'E'"),
+        })
+    }
+
+    #[test]
+    fn parses_cells_and_markers() {
+        let (grid, markers) = parse_walls("#S.\n.E#\n\n").unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.cells, [true, false, false, false, false, true]);
+        assert_eq!(markers.only('S').unwrap(), GridPos { row: 0, col: 1 });
+        assert_eq!(markers.positions('E'), [GridPos { row: 1, col: 1 }]);
+
+        let (_, markers) = parse_walls("S.S").unwrap();
+        assert_eq!(
+            markers.only('S').unwrap_err(),
+            "Expected one 'S' in the grid; found 2 at (r:0, c:0), (r:0, c:2)"
+        );
+        assert_eq!(markers.only('E').unwrap_err(), "No 'E' in the grid");
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_bad_cell() {
+        let bad_cell = parse_walls("#.\n.x").unwrap_err();
+        assert_eq!(bad_cell.line_number, 2);
+        assert_eq!(bad_cell.offending_text(), "x");
+        assert_eq!(bad_cell.expected, "'.', '#', 'S' or 'E'");
+
+        let short_row = parse_walls("#..\n.#").unwrap_err();
+        assert_eq!(
+            (short_row.line_number, short_row.columns.clone()),
+            (2, 2..2)
+        );
+        assert_eq!(short_row.expected, "a row 3 wide like the first row");
+
+        let lines = [
+            String::from("header"),
+            String::from("#."),
+            String::from("#.#"),
+        ];
+        let section = input_helpers::Section {
+            first_line_number: 5,
+            lines: &lines[1..],
+        };
+        let long_row = Grid::parse(section, |_, _| Ok::<_, String>(0)).unwrap_err();
+        assert_eq!(long_row.line_number, 6);
+        assert_eq!(long_row.offending_text(), "#");
+    }
+}
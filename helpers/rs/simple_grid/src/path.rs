@@ -0,0 +1,450 @@
+//! Shortest paths over any state type, not just grid positions: a search starts from one state
+//! and asks `successors` where it can go next (and, for weighted searches, at what cost). A state
+//! can be a [`GridPos`](crate::GridPos), or e.g. a `(GridPos, Dir4)` when turning costs something.
+//!
+//! Every search stops early once `is_goal` accepts a state it's about to expand; pass `|_| false`
+//! to find the distance to everything reachable.
+//!
+//! Ties are kept: a state remembers every predecessor a shortest path to it can come from, so all
+//! of the shortest paths can be walked, not just one. That only works when every step costs
+//! something; with free steps, states could be each other's predecessors.
+
+use crate::GridPos;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A search state that stands on a grid cell, so the cells shortest paths cross can be counted
+/// however many other states (e.g. facings) share each cell.
+pub trait AtCell {
+    fn cell(&self) -> GridPos;
+}
+
+impl AtCell for GridPos {
+    fn cell(&self) -> GridPos {
+        *self
+    }
+}
+
+impl<T> AtCell for (GridPos, T) {
+    fn cell(&self) -> GridPos {
+        self.0
+    }
+}
+
+/// What a search found: the distance from the start to every state it reached, and the
+/// predecessors to walk back along to rebuild its shortest paths. Where two ways to a state tie,
+/// both are kept, so this is a DAG rather than a tree.
+#[derive(Clone, Debug)]
+pub struct Paths<S>
+where
+    S: Clone + Eq + Hash,
+{
+    start: S,
+    dist: HashMap<S, usize>,
+    prev: HashMap<S, Vec<S>>,
+    goal: Option<S>,
+}
+
+impl<S> Paths<S>
+where
+    S: Clone + Eq + Hash,
+{
+    fn new(start: S) -> Paths<S> {
+        let mut dist = HashMap::new();
+        dist.insert(start.clone(), 0);
+        Paths {
+            start,
+            dist,
+            prev: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// The first state `is_goal` accepted, if the search got to one.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// The shortest distance to `state`, or `None` if it wasn't reached. When the search stopped
+    /// at a goal, states it hadn't expanded yet may only have an upper bound.
+    pub fn dist(&self, state: &S) -> Option<usize> {
+        self.dist.get(state).copied()
+    }
+
+    /// Every reached state and its distance from the start.
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.dist
+    }
+
+    /// The state a shortest path to `state` came from (the first found, if several tie); `None`
+    /// for the start or unreached states.
+    pub fn prev(&self, state: &S) -> Option<&S> {
+        self.prevs(state).first()
+    }
+
+    /// Every state a shortest path to `state` can come from. When the search stopped at a goal,
+    /// this is only complete for states it had expanded and for the goal itself (A* looks a little
+    /// past the goal to make sure of that).
+    pub fn prevs(&self, state: &S) -> &[S] {
+        self.prev.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// A shortest path from the start to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.dist.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(prev) = self.prev(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path from the start to `state`, one at a time, each including both ends.
+    /// There can be exponentially many, so prefer [`Paths::all_shortest_path_states`] for counting
+    /// what they cross.
+    pub fn all_paths_to(&self, state: &S) -> AllPaths<'_, S> {
+        let mut all_paths = AllPaths {
+            paths: self,
+            stack: vec![],
+        };
+        if self.dist.contains_key(state) {
+            all_paths.stack.push((state.clone(), 0));
+            all_paths.descend();
+        }
+        all_paths
+    }
+
+    /// Every state on any shortest path to any of `ends`, ends included.
+    pub fn all_shortest_path_states<E>(&self, ends: E) -> HashSet<S>
+    where
+        E: IntoIterator<Item = S>,
+    {
+        let mut to_check: Vec<S> = ends
+            .into_iter()
+            .filter(|end| self.dist.contains_key(end))
+            .collect();
+        let mut on_path: HashSet<S> = to_check.iter().cloned().collect();
+
+        while let Some(state) = to_check.pop() {
+            for prev in self.prevs(&state) {
+                if on_path.insert(prev.clone()) {
+                    to_check.push(prev.clone());
+                }
+            }
+        }
+
+        on_path
+    }
+
+    /// Every grid cell on any shortest path to any of `ends`.
+    pub fn all_shortest_path_cells<E>(&self, ends: E) -> HashSet<GridPos>
+    where
+        S: AtCell,
+        E: IntoIterator<Item = S>,
+    {
+        self.all_shortest_path_states(ends)
+            .iter()
+            .map(AtCell::cell)
+            .collect()
+    }
+
+    /// A shortest path to the goal, if one was found.
+    pub fn path_to_goal(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+
+    /// Records `dist` to `state` via `prev` if it's no longer than what we had. Returns whether
+    /// it was shorter, i.e. whether `state` needs to be searched from (again).
+    fn relax(&mut self, state: &S, prev: &S, dist: usize) -> bool {
+        match self.dist.get(state) {
+            Some(old_dist) if *old_dist < dist => false,
+            Some(old_dist) if *old_dist == dist => {
+                if *state != self.start {
+                    let prevs = self.prev.entry(state.clone()).or_default();
+                    if !prevs.contains(prev) {
+                        prevs.push(prev.clone());
+                    }
+                }
+                false
+            }
+            _ => {
+                self.dist.insert(state.clone(), dist);
+                self.prev.insert(state.clone(), vec![prev.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// The iterator from [`Paths::all_paths_to`].
+pub struct AllPaths<'a, S>
+where
+    S: Clone + Eq + Hash,
+{
+    paths: &'a Paths<S>,
+    /// The path being walked back from the end, with which of each state's predecessors it took.
+    stack: Vec<(S, usize)>,
+}
+
+impl<S> AllPaths<'_, S>
+where
+    S: Clone + Eq + Hash,
+{
+    /// Follows the chosen predecessors back from the top of the stack to the start.
+    fn descend(&mut self) {
+        while let Some((state, prev_idx)) = self.stack.last() {
+            match self.paths.prevs(state).get(*prev_idx) {
+                Some(prev) => self.stack.push((prev.clone(), 0)),
+                None => break,
+            }
+        }
+    }
+}
+
+impl<S> Iterator for AllPaths<'_, S>
+where
+    S: Clone + Eq + Hash,
+{
+    type Item = Vec<S>;
+
+    fn next(&mut self) -> Option<Vec<S>> {
+        if self.stack.is_empty() {
+            return None;
+        }
+        let path: Vec<S> = self
+            .stack
+            .iter()
+            .rev()
+            .map(|(state, _)| state.clone())
+            .collect();
+
+        // move on to the next choice of predecessor, nearest the start first
+        self.stack.pop();
+        while let Some((state, prev_idx)) = self.stack.last_mut() {
+            *prev_idx += 1;
+            if *prev_idx < self.paths.prevs(state).len() {
+                self.descend();
+                break;
+            }
+            self.stack.pop();
+        }
+
+        Some(path)
+    }
+}
+
+/// Breadth-first search, for when every step costs 1.
+pub fn bfs<S, I, F, G>(start: S, mut successors: F, mut is_goal: G) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, dist)) = queue.pop_front() {
+        if is_goal(&state) {
+            paths.goal = Some(state);
+            break;
+        }
+
+        for next in successors(&state) {
+            if paths.relax(&next, &state, dist + 1) {
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm, for steps with different (non-negative) costs.
+pub fn dijkstra<S, I, F, G>(start: S, successors: F, is_goal: G) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search: Dijkstra's, but trying the states `heuristic` says are closest to a goal first.
+/// The heuristic must never overestimate the remaining cost, and shouldn't drop by more than a
+/// step's cost across that step (e.g. the manhattan distance on a grid where steps cost 1).
+pub fn astar<S, I, F, H, G>(
+    start: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+    F: FnMut(&S) -> I,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut paths = Paths::new(start.clone());
+
+    // The heap holds (estimated total cost, index into `queued`) so states don't need to be Ord.
+    // A state gets queued again whenever a shorter way to it turns up; the stale entries are
+    // skipped when they're popped.
+    let mut queued: Vec<(S, usize)> = vec![];
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((heuristic(&start), 0)));
+    queued.push((start, 0));
+
+    // Once the goal turns up, the search carries on through the states that might still be a
+    // tied way into it: with a heuristic that never overestimates, those are the ones whose
+    // estimate isn't past the goal's distance.
+    let mut goal_dist = None;
+    while let Some(Reverse((estimate, idx))) = heap.pop() {
+        if goal_dist.is_some_and(|goal_dist| estimate > goal_dist) {
+            break;
+        }
+        let (state, dist) = queued[idx].clone();
+        if paths.dist(&state).is_some_and(|best_dist| best_dist < dist) {
+            continue;
+        }
+        if goal_dist.is_none() && is_goal(&state) {
+            paths.goal = Some(state);
+            goal_dist = Some(dist);
+            continue;
+        }
+
+        for (next, cost) in successors(&state) {
+            let next_dist = dist + cost;
+            if paths.relax(&next, &state, next_dist) {
+                heap.push(Reverse((next_dist + heuristic(&next), queued.len())));
+                queued.push((next, next_dist));
+            }
+        }
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::{Grid, GridPos, Markers};
+
+    fn parse_walls(text: &str) -> Result<(Grid<bool>, Markers), input_helpers::ParseError> {
+        Grid::parse_with_markers(text, &['S', 'E'], |c, _pos| match c {
+            '#' => Ok(true),
+            '.' | 'S' | 'E' => Ok(false),
+            _ => Err("'.', '#', 'S' or 'E'"),
+        })
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        // S is at (0, 0) and E at (2, 0); the wall forces the path around through column 2
+        let (grid, markers) = parse_walls("S..\n##.\nE..").unwrap();
+        let start = markers.only('S').unwrap();
+        let end = markers.only('E').unwrap();
+        let open_neighbors = |pos: &GridPos| {
+            grid.neighbors4(*pos)
+                .filter(|neighbor| !grid.get_cell(neighbor.row, neighbor.col))
+                .collect::<Vec<_>>()
+        };
+
+        let everything = bfs(start, open_neighbors, |_| false);
+        assert_eq!(everything.goal(), None);
+        assert_eq!(everything.dist(&end), Some(6));
+        assert_eq!(everything.distances().len(), 7);
+        assert_eq!(everything.dist(&GridPos { row: 1, col: 0 }), None);
+
+        let to_end = bfs(start, open_neighbors, |pos| *pos == end);
+        assert_eq!(to_end.goal(), Some(&end));
+        let path = to_end.path_to_goal().unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(
+            (path[0], path[3], path[6]),
+            (start, GridPos { row: 1, col: 2 }, end)
+        );
+
+        // stepping onto the right column is expensive, but there's no other way
+        let weighted = |pos: &GridPos| {
+            open_neighbors(pos)
+                .into_iter()
+                .map(|next| (next, if next.col == 2 { 10 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+        let dijkstra = dijkstra(start, weighted, |pos| *pos == end);
+        assert_eq!(dijkstra.dist(&end), Some(1 + 10 + 10 + 10 + 1 + 1));
+
+        let manhattan =
+            |pos: &GridPos| ((pos.row - end.row).abs() + (pos.col - end.col).abs()) as usize;
+        let astar = astar(start, weighted, manhattan, |pos| *pos == end);
+        assert_eq!(astar.dist(&end), dijkstra.dist(&end));
+        assert_eq!(astar.path_to_goal(), dijkstra.path_to_goal());
+    }
+
+    #[test]
+    fn keeps_every_shortest_path() {
+        // two ways around the wall in the middle, and a dead end that isn't on either
+        let (grid, markers) = parse_walls("S...\n.#..\n..E#").unwrap();
+        let start = markers.only('S').unwrap();
+        let end = markers.only('E').unwrap();
+        let paths = bfs(
+            start,
+            |pos: &GridPos| {
+                grid.neighbors4(*pos)
+                    .filter(|neighbor| !grid.get_cell(neighbor.row, neighbor.col))
+                    .collect::<Vec<_>>()
+            },
+            |_| false,
+        );
+
+        assert_eq!(paths.dist(&end), Some(4));
+        assert_eq!(paths.prevs(&end).len(), 2);
+        let mut all_paths: Vec<Vec<GridPos>> = paths.all_paths_to(&end).collect();
+        all_paths.sort_by_key(|path| path[1].col);
+        assert_eq!(all_paths.len(), 2);
+        assert_eq!(all_paths[0][1], GridPos { row: 1, col: 0 });
+        assert_eq!(all_paths[1][2], GridPos { row: 0, col: 2 });
+
+        let cells = paths.all_shortest_path_cells([end]);
+        assert_eq!(cells.len(), 8);
+        assert!(!cells.contains(&GridPos { row: 0, col: 3 }));
+        assert_eq!(paths.all_paths_to(&start).collect::<Vec<_>>(), [[start]]);
+        assert_eq!(paths.all_paths_to(&GridPos { row: 1, col: 1 }).count(), 0);
+    }
+
+    #[test]
+    fn astar_keeps_ties_into_the_goal() {
+        // S -1-> A -2-> G and S -1-> B -1-> C -1-> G both cost 3, but G is queued before C is
+        // expanded, so A* has to look past G to find C
+        let successors = |state: &char| match state {
+            'S' => vec![('A', 1), ('B', 1)],
+            'A' => vec![('G', 2)],
+            'B' => vec![('C', 1)],
+            'C' => vec![('G', 1)],
+            _ => vec![],
+        };
+        let heuristic = |state: &char| match state {
+            'S' => 3,
+            'A' | 'B' => 2,
+            'C' => 1,
+            _ => 0,
+        };
+        let paths = astar('S', successors, heuristic, |state| *state == 'G');
+        assert_eq!(paths.goal(), Some(&'G'));
+        assert_eq!(paths.dist(&'G'), Some(3));
+        assert_eq!(paths.prevs(&'G'), &['A', 'C']);
+        assert_eq!(paths.all_paths_to(&'G').count(), 2);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{Dir8, Grid, GridPos};
+
+/// The cells along a straight line out of a grid position, nearest first, up to the edge. The
+/// position it starts from isn't included. Made by [`Grid::ray`] and [`Grid::ray_by`].
+pub struct Ray<'a, T>
+where
+    T: Clone + Copy,
+{
+    grid: &'a Grid<T>,
+    pos: GridPos,
+    step: (isize, isize),
+}
+
+impl<'a, T> Iterator for Ray<'a, T>
+where
+    T: Clone + Copy,
+{
+    type Item = (GridPos, T);
+
+    fn next(&mut self) -> Option<(GridPos, T)> {
+        let next_pos = GridPos {
+            row: self.pos.row + self.step.0,
+            col: self.pos.col + self.step.1,
+        };
+        let cell = self.grid.get(next_pos)?;
+        self.pos = next_pos;
+        Some((next_pos, cell))
+    }
+}
+
+fn gcd(mut n: isize, mut m: isize) -> isize {
+    while m != 0 {
+        let rem = n % m;
+        n = m;
+        m = rem;
+    }
+    n.abs()
+}
+
+/// The smallest (row, col) step that gets from `from` to `to` only landing on whole grid
+/// positions, e.g. (1, 2) from (0, 0) to (3, 6). `(0, 0)` if they're the same position.
+pub fn lattice_step(from: GridPos, to: GridPos) -> (isize, isize) {
+    let (row_diff, col_diff) = (to.row - from.row, to.col - from.col);
+    match gcd(row_diff, col_diff) {
+        0 => (0, 0),
+        divisor => (row_diff / divisor, col_diff / divisor),
+    }
+}
+
+/// Every whole grid position on the segment from `from` to `to`, both ends included.
+pub fn lattice_points(from: GridPos, to: GridPos) -> impl Iterator<Item = GridPos> {
+    let (row_step, col_step) = lattice_step(from, to);
+    let steps = if (row_step, col_step) == (0, 0) {
+        0
+    } else if row_step != 0 {
+        (to.row - from.row) / row_step
+    } else {
+        (to.col - from.col) / col_step
+    };
+
+    (0..=steps).map(move |step| GridPos {
+        row: from.row + step * row_step,
+        col: from.col + step * col_step,
+    })
+}
+
+impl<T> Grid<T>
+where
+    T: Clone + Copy,
+{
+    /// The cells from `pos` out to the edge of the grid in direction `dir`, not including `pos`.
+    pub fn ray(&self, pos: GridPos, dir: Dir8) -> Ray<'_, T> {
+        self.ray_by(pos, dir.offset())
+    }
+
+    /// Like [`Grid::ray`], but moving by any (row, col) step at a time, e.g. a knight's move.
+    pub fn ray_by(&self, pos: GridPos, step: (isize, isize)) -> Ray<'_, T> {
+        assert!(step != (0, 0), "a ray has to go somewhere");
+        Ray {
+            grid: self,
+            pos,
+            step,
+        }
+    }
+
+    /// The position of the first cell in sight from `pos` in direction `dir` that `is_visible`
+    /// picks out, looking past every cell it doesn't (e.g. the first seat across the floor).
+    pub fn first_visible<F>(&self, pos: GridPos, dir: Dir8, mut is_visible: F) -> Option<GridPos>
+    where
+        F: FnMut(T) -> bool,
+    {
+        self.ray(pos, dir)
+            .find(|(_, cell)| is_visible(*cell))
+            .map(|(visible_pos, _)| visible_pos)
+    }
+
+    /// Every in-bounds whole grid position on the line through `a` and `b`, which carries on past
+    /// both of them to the edges of the grid. Ordered along the line, from the edge beyond `a`
+    /// to the edge beyond `b`. `a` has to be in bounds; `b` doesn't.
+    pub fn line_through(&self, a: GridPos, b: GridPos) -> Vec<GridPos> {
+        assert!(
+            self.get(a).is_some(),
+            "the line has to start inside the grid"
+        );
+        let (row_step, col_step) = lattice_step(a, b);
+        assert!(
+            (row_step, col_step) != (0, 0),
+            "a line needs two different points"
+        );
+
+        let mut line: Vec<GridPos> = self
+            .ray_by(a, (-row_step, -col_step))
+            .map(|(pos, _)| pos)
+            .collect();
+        line.reverse();
+        line.push(a);
+        line.extend(self.ray_by(a, (row_step, col_step)).map(|(pos, _)| pos));
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lattice_points, lattice_step};
+    use crate::{Dir8, Grid, GridPos};
+
+    #[test]
+    fn casts_rays_and_lattice_lines() {
+        let grid = Grid::parse(".L.\n...\n#.L", |c, _pos| Ok::<_, String>(c)).unwrap();
+        let corner = GridPos { row: 0, col: 0 };
+        let diagonal: Vec<char> = grid.ray(corner, Dir8::SE).map(|(_, c)| c).collect();
+        assert_eq!(diagonal, vec!['.', 'L']);
+        assert_eq!(grid.ray(corner, Dir8::N).count(), 0);
+
+        let is_seat = |c| c != '.';
+        assert_eq!(
+            grid.first_visible(corner, Dir8::SE, is_seat),
+            Some(GridPos { row: 2, col: 2 })
+        );
+        assert_eq!(
+            grid.first_visible(corner, Dir8::E, is_seat),
+            Some(GridPos { row: 0, col: 1 })
+        );
+        assert_eq!(
+            grid.first_visible(GridPos { row: 1, col: 1 }, Dir8::NW, is_seat),
+            None
+        );
+        let knight: Vec<GridPos> = grid.ray_by(corner, (2, 1)).map(|(pos, _)| pos).collect();
+        assert_eq!(knight, vec![GridPos { row: 2, col: 1 }]);
+
+        let from = GridPos { row: 0, col: 0 };
+        let to = GridPos { row: -3, col: 6 };
+        assert_eq!(lattice_step(from, to), (-1, 2));
+        assert_eq!(lattice_step(from, from), (0, 0));
+        let points: Vec<GridPos> = lattice_points(from, to).collect();
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[1], GridPos { row: -1, col: 2 });
+        assert_eq!(lattice_points(from, from).count(), 1);
+
+        let wide = Grid::new(7, 4, ());
+        let line = wide.line_through(GridPos { row: 1, col: 2 }, GridPos { row: 2, col: 4 });
+        let expected: Vec<GridPos> = [(0, 0), (1, 2), (2, 4), (3, 6)]
+            .iter()
+            .map(|(row, col)| GridPos {
+                row: *row,
+                col: *col,
+            })
+            .collect();
+        assert_eq!(line, expected);
+    }
+}
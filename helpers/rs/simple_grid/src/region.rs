@@ -0,0 +1,171 @@
+use crate::{Dir4, Grid, GridPos};
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+/// A set of cells, e.g. one of [`Grid::components`], measured as a shape: how much fence it takes
+/// to go round it, how many straight sides that fence has, and so on. The cells needn't be
+/// connected or come from any particular grid.
+#[derive(Clone, Debug, Default)]
+pub struct Region {
+    cells: Vec<GridPos>,
+    cell_set: HashSet<GridPos>,
+}
+
+impl FromIterator<GridPos> for Region {
+    fn from_iter<I: IntoIterator<Item = GridPos>>(cells: I) -> Region {
+        let mut region = Region::default();
+        for pos in cells {
+            if region.cell_set.insert(pos) {
+                region.cells.push(pos);
+            }
+        }
+        region
+    }
+}
+
+impl Region {
+    /// The cells, in the order they were given.
+    pub fn cells(&self) -> &[GridPos] {
+        &self.cells
+    }
+
+    pub fn contains(&self, pos: GridPos) -> bool {
+        self.cell_set.contains(&pos)
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// How many cell edges lie between a cell in the region and one outside it, holes included.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|pos| {
+                Dir4::ALL
+                    .iter()
+                    .filter(|dir| !self.contains(*pos + **dir))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// How many straight sides the perimeter has, holes included. A polygon has as many sides as
+    /// corners, so this counts corners: for each cell and each pair of adjacent directions, an
+    /// outside corner when neither neighbor is in the region, and an inside corner when both are
+    /// but the diagonal between them isn't. Where two cells only touch diagonally, the sides
+    /// meeting there count separately.
+    pub fn side_count(&self) -> usize {
+        let mut corners = 0;
+        for pos in &self.cells {
+            for dir in Dir4::ALL {
+                let next_dir = dir.turn_cw();
+                let has_dir = self.contains(*pos + dir);
+                let has_next_dir = self.contains(*pos + next_dir);
+                let has_diagonal = self.contains(*pos + dir + next_dir);
+                if (!has_dir && !has_next_dir) || (has_dir && has_next_dir && !has_diagonal) {
+                    corners += 1;
+                }
+            }
+        }
+        corners
+    }
+
+    /// The top-left and bottom-right corners (inclusive) of the smallest box around the region,
+    /// or `None` if it's empty.
+    pub fn bounding_box(&self) -> Option<(GridPos, GridPos)> {
+        let first = *self.cells.first()?;
+        Some(self.cells.iter().fold((first, first), |(min, max), pos| {
+            (
+                GridPos {
+                    row: min.row.min(pos.row),
+                    col: min.col.min(pos.col),
+                },
+                GridPos {
+                    row: max.row.max(pos.row),
+                    col: max.col.max(pos.col),
+                },
+            )
+        }))
+    }
+
+    /// Whether any cells outside the region are cut off from the outside of its bounding box, i.e.
+    /// can't get out by orthogonal steps through other cells outside the region.
+    pub fn has_holes(&self) -> bool {
+        let (min, max) = match self.bounding_box() {
+            Some(bounding_box) => bounding_box,
+            None => return false,
+        };
+
+        // the bounding box plus a ring of outside cells, so the outside is all connected
+        let width = (max.col - min.col + 3) as usize;
+        let height = (max.row - min.row + 3) as usize;
+        let mut in_region = Grid::new(width, height, false);
+        for pos in &self.cells {
+            *in_region.get_cell_mut(pos.row - min.row + 1, pos.col - min.col + 1) = true;
+        }
+
+        let outside = in_region.flood_fill(GridPos { row: 0, col: 0 }, |_, to| !to);
+        outside.len() + self.area() < width * height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Region;
+    use crate::{Grid, GridPos};
+
+    fn char_regions(text: &str) -> Vec<(char, Region)> {
+        let grid = Grid::parse(text, |c, _pos| Ok::<_, String>(c)).unwrap();
+        grid.components(|a, b| a == b)
+            .cells
+            .into_iter()
+            .map(|cells| {
+                (
+                    grid.get_cell(cells[0].row, cells[0].col),
+                    cells.into_iter().collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn measures_regions() {
+        let measure = |region: &Region| (region.area(), region.perimeter(), region.side_count());
+        let regions = char_regions("AAAA\nBBCD\nBBCC\nEEEC");
+        let measured: Vec<_> = regions
+            .iter()
+            .map(|(c, region)| (*c, measure(region)))
+            .collect();
+        assert_eq!(
+            measured,
+            [
+                ('A', (4, 10, 4)),
+                ('B', (4, 8, 4)),
+                ('C', (4, 10, 8)),
+                ('D', (1, 4, 4)),
+                ('E', (3, 8, 4)),
+            ]
+        );
+        assert!(!regions.iter().any(|(_, region)| region.has_holes()));
+        assert_eq!(
+            regions[2].1.bounding_box(),
+            Some((GridPos { row: 1, col: 2 }, GridPos { row: 3, col: 3 }))
+        );
+
+        let regions = char_regions("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO");
+        let (_, outer) = &regions[0];
+        assert_eq!(measure(outer), (21, 36, 20));
+        assert!(outer.has_holes());
+        assert!(!regions[1].1.has_holes());
+
+        // cells that only touch diagonally don't enclose anything, but their sides don't join up
+        let diagonal: Region = [GridPos { row: 0, col: 0 }, GridPos { row: 1, col: 1 }]
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(measure(&diagonal), (2, 8, 8));
+        assert!(!diagonal.has_holes());
+        assert_eq!(Region::default().bounding_box(), None);
+    }
+}
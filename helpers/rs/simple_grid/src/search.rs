@@ -0,0 +1,205 @@
+//! Word-search style queries: finding every place a word or a small 2D pattern shows up in a grid.
+
+use crate::{Dir8, Grid, GridPos, Orientation};
+use input_helpers::ParseError;
+use std::collections::HashSet;
+
+/// Where [`find_word`] found the word: its first cell, and the way the rest of it runs.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct WordMatch {
+    pub start: GridPos,
+    pub dir: Dir8,
+}
+
+/// Where [`find_stencil`] found the stencil: the top-left cell it covered, and which way round
+/// the stencil was.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct StencilMatch {
+    pub top_left: GridPos,
+    pub orientation: Orientation,
+}
+
+/// A small rectangular pattern to look for, where `None` cells are wildcards that match anything.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Stencil<T>
+where
+    T: Clone + Copy,
+{
+    pub cells: Grid<Option<T>>,
+}
+
+impl Stencil<char> {
+    /// One cell per char, where `wildcard` (e.g. `.`) matches anything.
+    ///
+    /// ```
+    /// use simple_grid::search::Stencil;
+    ///
+    /// let stencil = Stencil::parse("M.S\n.A.\nM.S", '.').unwrap();
+    /// assert_eq!(stencil.cells.get_cell(1, 1), Some('A'));
+    /// assert_eq!(stencil.cells.get_cell(0, 1), None);
+    /// ```
+    pub fn parse(text: &str, wildcard: char) -> Result<Stencil<char>, ParseError> {
+        let cells = Grid::parse(text, |c, _pos| {
+            Ok::<_, String>(if c == wildcard { None } else { Some(c) })
+        })?;
+        Ok(Stencil { cells })
+    }
+}
+
+/// Every place `word` can be read in `grid` going in any of `dirs`, in reading order of where it
+/// starts. Each run of cells is only found once, so a palindrome isn't also found backwards and a
+/// one-letter word is found in the first of `dirs`.
+pub fn find_word<T>(grid: &Grid<T>, word: &[T], dirs: &[Dir8]) -> Vec<WordMatch>
+where
+    T: Clone + Copy + PartialEq,
+{
+    let (first, rest) = match word.split_first() {
+        Some(split) => split,
+        None => return vec![],
+    };
+
+    let mut matches = vec![];
+    // a straight run of cells is pinned down by its two ends, whichever way it's read
+    let mut found_ends = HashSet::new();
+    for idx in 0..grid.cells.len() {
+        if grid.cells[idx] != *first {
+            continue;
+        }
+
+        let start = Grid::<T>::cell_pos_from_idx(grid.width, grid.height, idx);
+        for dir in dirs {
+            let run: Vec<(GridPos, T)> = grid.ray(start, *dir).take(rest.len()).collect();
+            if !run.iter().map(|(_, cell)| *cell).eq(rest.iter().copied()) {
+                continue;
+            }
+
+            let end = run.last().map_or(start, |(pos, _)| *pos);
+            let ends = if (start.row, start.col) <= (end.row, end.col) {
+                (start, end)
+            } else {
+                (end, start)
+            };
+            if found_ends.insert(ends) {
+                matches.push(WordMatch { start, dir: *dir });
+            }
+        }
+    }
+    matches
+}
+
+/// Every place `stencil` fits over `grid` when turned to any of `orientations`, in reading order
+/// of the top-left corner. Orientations that leave the stencil looking the same (e.g. flipping a
+/// symmetric one) are only tried once, so each match is only found once.
+pub fn find_stencil<T>(
+    grid: &Grid<T>,
+    stencil: &Stencil<T>,
+    orientations: &[Orientation],
+) -> Vec<StencilMatch>
+where
+    T: Clone + Copy + PartialEq,
+{
+    let oriented_stencils = stencil.cells.distinct_orientations(orientations);
+    let fits = |cell: T, stencil_cell: Option<T>| stencil_cell.is_none_or(|c| c == cell);
+
+    let mut matches = vec![];
+    // a turned stencil can be a different shape, so each orientation gets its own windows
+    for (orientation, oriented) in &oriented_stencils {
+        for window in grid.windows(oriented.width, oriented.height) {
+            if window.matches(oriented, fits) {
+                matches.push(StencilMatch {
+                    top_left: window.top_left(),
+                    orientation: *orientation,
+                });
+            }
+        }
+    }
+
+    matches.sort_by_key(|m| (m.top_left.row, m.top_left.col));
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_stencil, find_word, Stencil, StencilMatch, WordMatch};
+    use crate::{Dir8, Grid, GridPos, Orientation};
+
+    #[test]
+    fn searches_for_words_and_stencils() {
+        let grid = Grid::parse("CAT.\nA.A.\nTACX", |c, _pos| Ok::<_, String>(c)).unwrap();
+        let cat: Vec<char> = "CAT".chars().collect();
+        let start = GridPos { row: 0, col: 0 };
+        let end = GridPos { row: 2, col: 2 };
+        assert_eq!(
+            find_word(&grid, &cat, &Dir8::ALL),
+            vec![
+                WordMatch {
+                    start,
+                    dir: Dir8::E
+                },
+                WordMatch {
+                    start,
+                    dir: Dir8::S
+                },
+                WordMatch {
+                    start: end,
+                    dir: Dir8::N
+                },
+                WordMatch {
+                    start: end,
+                    dir: Dir8::W
+                },
+            ]
+        );
+        assert_eq!(find_word(&grid, &cat, &[Dir8::E, Dir8::SE]).len(), 1);
+        assert_eq!(
+            find_word(&grid, &['X'], &Dir8::ALL),
+            vec![WordMatch {
+                start: GridPos { row: 2, col: 3 },
+                dir: Dir8::N
+            }]
+        );
+        assert!(find_word(&grid, &[], &Dir8::ALL).is_empty());
+        let noon = Grid::parse("NOON\n.O..", |c, _pos| Ok::<_, String>(c)).unwrap();
+        assert_eq!(
+            find_word(&noon, &['N', 'O', 'O', 'N'], &Dir8::ALL),
+            vec![WordMatch {
+                start: GridPos { row: 0, col: 0 },
+                dir: Dir8::E
+            }]
+        );
+        // along the top row, down the middle and diagonally, each once rather than both ways
+        assert_eq!(find_word(&noon, &['O', 'O'], &Dir8::ALL).len(), 3);
+
+        // an L of A's, with the middle a wildcard
+        let corner = Stencil::parse("A.\n?A", '.').unwrap();
+        assert!(find_stencil(&grid, &corner, &Orientation::ALL).is_empty());
+        let corner = Stencil::parse("A.\nTA", '.').unwrap();
+        let found = find_stencil(&grid, &corner, &Orientation::ALL);
+        let half_turn = Orientation {
+            quarter_turns: 2,
+            flipped: false,
+        };
+        assert_eq!(
+            found,
+            vec![
+                StencilMatch {
+                    top_left: GridPos { row: 0, col: 1 },
+                    orientation: half_turn,
+                },
+                StencilMatch {
+                    top_left: GridPos { row: 1, col: 0 },
+                    orientation: Orientation::IDENTITY,
+                },
+            ]
+        );
+
+        // turned, a tall stencil lies across the rows
+        let tall = Stencil::parse("C\n.\nT", '.').unwrap();
+        let found = find_stencil(&grid, &tall, &Orientation::ALL);
+        let found: Vec<(isize, isize, u8)> = found
+            .iter()
+            .map(|m| (m.top_left.row, m.top_left.col, m.orientation.quarter_turns))
+            .collect();
+        assert_eq!(found, vec![(0, 0, 0), (0, 0, 3), (0, 2, 2), (2, 0, 1)]);
+    }
+}
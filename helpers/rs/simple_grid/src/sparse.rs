@@ -0,0 +1,231 @@
+use crate::{Dir4, Dir8, GridPos};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// A position that a [`SparseGrid`] can be keyed by.
+pub trait Coord: Copy + Eq + Hash {
+    /// The steps from a position to every position next to it, diagonals included. A grid works
+    /// these out once and reuses them, since there can be a lot of them in more dimensions.
+    fn neighbor_offsets() -> Vec<Self>;
+
+    /// `self` moved by `offset`.
+    fn offset_by(self, offset: Self) -> Self;
+
+    /// The smallest value along each axis of `self` and `other`.
+    fn min_each(self, other: Self) -> Self;
+
+    /// The largest value along each axis of `self` and `other`.
+    fn max_each(self, other: Self) -> Self;
+}
+
+impl Coord for GridPos {
+    fn neighbor_offsets() -> Vec<GridPos> {
+        Dir8::ALL
+            .iter()
+            .map(|dir| {
+                let (row, col) = dir.offset();
+                GridPos { row, col }
+            })
+            .collect()
+    }
+
+    fn offset_by(self, offset: GridPos) -> GridPos {
+        GridPos {
+            row: self.row + offset.row,
+            col: self.col + offset.col,
+        }
+    }
+
+    fn min_each(self, other: GridPos) -> GridPos {
+        GridPos {
+            row: self.row.min(other.row),
+            col: self.col.min(other.col),
+        }
+    }
+
+    fn max_each(self, other: GridPos) -> GridPos {
+        GridPos {
+            row: self.row.max(other.row),
+            col: self.col.max(other.col),
+        }
+    }
+}
+
+/// An unbounded grid where every cell starts out as `default`, and only the others are stored.
+/// For puzzles on an infinite plane (or space) where anything interesting happens in a small,
+/// growing patch of it.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T, P = GridPos>
+where
+    T: Clone + Copy + PartialEq,
+    P: Coord,
+{
+    default: T,
+    cells: HashMap<P, T>,
+    bounds: Option<(P, P)>,
+    neighbor_offsets: Vec<P>,
+}
+
+impl<T, P> SparseGrid<T, P>
+where
+    T: Clone + Copy + PartialEq,
+    P: Coord,
+{
+    pub fn new(default: T) -> SparseGrid<T, P> {
+        SparseGrid {
+            default,
+            cells: HashMap::new(),
+            bounds: None,
+            neighbor_offsets: P::neighbor_offsets(),
+        }
+    }
+
+    pub fn default_value(&self) -> T {
+        self.default
+    }
+
+    pub fn get(&self, pos: P) -> T {
+        self.cells.get(&pos).copied().unwrap_or(self.default)
+    }
+
+    pub fn set(&mut self, pos: P, value: T) {
+        if value == self.default {
+            self.cells.remove(&pos);
+            return;
+        }
+
+        self.cells.insert(pos, value);
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min_each(pos), max.max_each(pos)),
+            None => (pos, pos),
+        });
+    }
+
+    /// Every position next to `pos`, diagonals included. There's no edge to stop at.
+    pub fn neighbors(&self, pos: P) -> impl Iterator<Item = P> + '_ {
+        self.neighbor_offsets
+            .iter()
+            .map(move |offset| pos.offset_by(*offset))
+    }
+
+    /// Every cell that isn't the default value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (P, T)> + '_ {
+        self.cells.iter().map(|(pos, value)| (*pos, *value))
+    }
+
+    /// How many cells aren't the default value.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The lowest and highest corners (inclusive) of a box around every cell that's ever been set
+    /// to something other than the default, or `None` if none has. It grows as cells are set, but
+    /// doesn't shrink when they're set back to the default.
+    pub fn bounding_box(&self) -> Option<(P, P)> {
+        self.bounds
+    }
+}
+
+impl<T> SparseGrid<T, GridPos>
+where
+    T: Clone + Copy + PartialEq,
+{
+    pub fn get_cell(&self, row: isize, col: isize) -> T {
+        self.get(GridPos { row, col })
+    }
+
+    pub fn set_cell(&mut self, row: isize, col: isize, value: T) {
+        self.set(GridPos { row, col }, value)
+    }
+
+    /// The orthogonal neighbors of `pos`, clockwise from north.
+    pub fn neighbors4(&self, pos: GridPos) -> impl Iterator<Item = GridPos> {
+        Dir4::ALL.iter().map(move |dir| pos + *dir)
+    }
+
+    /// The neighbors of `pos` including diagonals, clockwise from north.
+    pub fn neighbors8(&self, pos: GridPos) -> impl Iterator<Item = GridPos> {
+        Dir8::ALL.iter().map(move |dir| pos + *dir)
+    }
+}
+
+/// Two sparse grids are equal when every cell is, whatever their bounding boxes.
+impl<T, P> PartialEq for SparseGrid<T, P>
+where
+    T: Clone + Copy + PartialEq,
+    P: Coord,
+{
+    fn eq(&self, other: &SparseGrid<T, P>) -> bool {
+        self.default == other.default && self.cells == other.cells
+    }
+}
+
+impl<T, P> Eq for SparseGrid<T, P>
+where
+    T: Clone + Copy + Eq,
+    P: Coord,
+{
+}
+
+impl<T, P> Hash for SparseGrid<T, P>
+where
+    T: Clone + Copy + Eq + Hash,
+    P: Coord,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // the cells come out of the map in any order, so combine their hashes in a way that
+        // doesn't depend on it
+        let cells_hash = self
+            .cells
+            .iter()
+            .map(|cell| {
+                let mut cell_hasher = DefaultHasher::new();
+                cell.hash(&mut cell_hasher);
+                cell_hasher.finish()
+            })
+            .fold(0u64, u64::wrapping_add);
+
+        self.default.hash(state);
+        self.cells.len().hash(state);
+        cells_hash.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SparseGrid;
+    use crate::GridPos;
+
+    #[test]
+    fn sparse_grids_grow_as_needed() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.bounding_box(), None);
+        assert_eq!(grid.get_cell(-1000, 1000), '.');
+
+        grid.set_cell(-3, 2, '#');
+        grid.set(GridPos { row: 4, col: -1 }, '#');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(
+            grid.bounding_box(),
+            Some((GridPos { row: -3, col: -1 }, GridPos { row: 4, col: 2 }))
+        );
+
+        // setting a cell back to the default forgets it, but the bounds don't shrink
+        grid.set_cell(4, -1, '.');
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            [(GridPos { row: -3, col: 2 }, '#')]
+        );
+        assert_eq!(grid.bounding_box().unwrap().1, GridPos { row: 4, col: 2 });
+
+        let pos = GridPos { row: -3, col: 2 };
+        assert_eq!(grid.neighbors4(pos).count(), 4);
+        assert_eq!(grid.neighbors8(pos).count(), 8);
+        assert_eq!(grid.neighbors(pos).count(), 8);
+    }
+}
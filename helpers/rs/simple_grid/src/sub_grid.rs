@@ -0,0 +1,176 @@
+use crate::{Grid, GridPos, Orientation};
+
+/// A rectangle of a [`Grid`], borrowed rather than copied, with its own coordinates: (0, 0) is
+/// its top-left cell.
+#[derive(Clone, Copy, Debug)]
+pub struct SubGrid<'a, T>
+where
+    T: Clone + Copy,
+{
+    grid: &'a Grid<T>,
+    top_left: GridPos,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a, T> SubGrid<'a, T>
+where
+    T: Clone + Copy,
+{
+    /// Where the view's (0, 0) is in the grid it's a view of.
+    pub fn top_left(&self) -> GridPos {
+        self.top_left
+    }
+
+    /// The cell at `pos` in the view's coordinates, or `None` if it's outside the view.
+    pub fn get(&self, pos: GridPos) -> Option<T> {
+        if pos.row < 0
+            || pos.col < 0
+            || pos.row as usize >= self.height
+            || pos.col as usize >= self.width
+        {
+            return None;
+        }
+
+        Some(
+            self.grid
+                .get_cell(self.top_left.row + pos.row, self.top_left.col + pos.col),
+        )
+    }
+
+    pub fn get_cell(&self, row: isize, col: isize) -> T {
+        self.get(GridPos { row, col })
+            .expect("position is outside the sub-grid")
+    }
+
+    /// A copy of the view as a grid of its own.
+    pub fn to_grid(&self) -> Grid<T> {
+        let mut cells = Vec::with_capacity(self.width * self.height);
+        for row in 0..self.height as isize {
+            for col in 0..self.width as isize {
+                cells.push(self.get_cell(row, col));
+            }
+        }
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    /// Whether the view is the same size as `pattern` and `cell_matches(cell, pattern_cell)`
+    /// for every cell.
+    pub fn matches<P, F>(&self, pattern: &Grid<P>, mut cell_matches: F) -> bool
+    where
+        P: Clone + Copy,
+        F: FnMut(T, P) -> bool,
+    {
+        if (self.width, self.height) != (pattern.width, pattern.height) {
+            return false;
+        }
+
+        (0..self.height as isize).all(|row| {
+            (0..self.width as isize)
+                .all(|col| cell_matches(self.get_cell(row, col), pattern.get_cell(row, col)))
+        })
+    }
+
+    /// The first orientation of the pattern that the view matches, if any. `orientations` is
+    /// usually from [`Grid::distinct_orientations`].
+    pub fn matching_orientation<P, F>(
+        &self,
+        orientations: &[(Orientation, Grid<P>)],
+        mut cell_matches: F,
+    ) -> Option<Orientation>
+    where
+        P: Clone + Copy,
+        F: FnMut(T, P) -> bool,
+    {
+        orientations
+            .iter()
+            .find(|(_, pattern)| self.matches(pattern, &mut cell_matches))
+            .map(|(orientation, _)| *orientation)
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone + Copy,
+{
+    /// A `width` x `height` view with `top_left` at its (0, 0), or `None` if it doesn't fit inside
+    /// the grid.
+    pub fn sub_grid(
+        &self,
+        top_left: GridPos,
+        width: usize,
+        height: usize,
+    ) -> Option<SubGrid<'_, T>> {
+        let fits = |start: isize, len: usize, grid_len: usize| {
+            start >= 0 && start as usize + len <= grid_len
+        };
+        if !fits(top_left.row, height, self.height) || !fits(top_left.col, width, self.width) {
+            return None;
+        }
+
+        Some(SubGrid {
+            grid: self,
+            top_left,
+            width,
+            height,
+        })
+    }
+
+    /// Every `width` x `height` view that fits inside the grid, in reading order of their top-left
+    /// corners.
+    pub fn windows(
+        &self,
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = SubGrid<'_, T>> + '_ {
+        let rows = (self.height + 1).saturating_sub(height);
+        let cols = (self.width + 1).saturating_sub(width);
+        (0..rows as isize).flat_map(move |row| {
+            (0..cols as isize).map(move |col| SubGrid {
+                grid: self,
+                top_left: GridPos { row, col },
+                width,
+                height,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, GridPos, Orientation};
+
+    #[test]
+    fn views_and_windows() {
+        let parse_chars = |text| Grid::parse(text, |c, _pos| Ok::<_, String>(c)).unwrap();
+        let x_orientations = parse_chars("M.S\n.A.\nM.S").distinct_orientations(&Orientation::ALL);
+
+        let big = parse_chars("SXM\nXAX\nSXM\nabc");
+        let view = big.sub_grid(GridPos { row: 1, col: 1 }, 2, 3).unwrap();
+        assert_eq!(view.get(GridPos { row: 0, col: 0 }), Some('A'));
+        assert_eq!(view.get(GridPos { row: 2, col: 1 }), Some('c'));
+        assert_eq!(view.get(GridPos { row: 0, col: 2 }), None);
+        assert_eq!(view.to_grid(), parse_chars("AX\nXM\nbc"));
+        assert!(big.sub_grid(GridPos { row: 2, col: 0 }, 3, 3).is_none());
+
+        let windows: Vec<_> = big.windows(3, 3).collect();
+        assert_eq!(windows.len(), 2);
+        let wildcard = |cell, pattern_cell| pattern_cell == '.' || cell == pattern_cell;
+        let half_turn = Orientation {
+            quarter_turns: 2,
+            flipped: false,
+        };
+        assert_eq!(
+            windows[0].matching_orientation(&x_orientations, wildcard),
+            Some(half_turn)
+        );
+        assert_eq!(
+            windows[1].matching_orientation(&x_orientations, wildcard),
+            None
+        );
+    }
+}
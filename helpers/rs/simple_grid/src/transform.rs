@@ -0,0 +1,176 @@
+use crate::Grid;
+
+/// One of the 8 ways a grid can be turned over and around: mirrored left to right or not, then
+/// turned clockwise some number of quarter turns.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct Orientation {
+    /// Clockwise quarter turns, from 0 to 3.
+    pub quarter_turns: u8,
+    /// Mirrored left to right before turning.
+    pub flipped: bool,
+}
+
+impl Orientation {
+    pub const IDENTITY: Orientation = Orientation {
+        quarter_turns: 0,
+        flipped: false,
+    };
+
+    /// The 4 turns, without any mirroring.
+    pub const ROTATIONS: [Orientation; 4] = [
+        Orientation::IDENTITY,
+        Orientation {
+            quarter_turns: 1,
+            flipped: false,
+        },
+        Orientation {
+            quarter_turns: 2,
+            flipped: false,
+        },
+        Orientation {
+            quarter_turns: 3,
+            flipped: false,
+        },
+    ];
+
+    /// Every orientation: the 4 turns, then the 4 turns of the mirror image.
+    pub const ALL: [Orientation; 8] = [
+        Orientation::ROTATIONS[0],
+        Orientation::ROTATIONS[1],
+        Orientation::ROTATIONS[2],
+        Orientation::ROTATIONS[3],
+        Orientation {
+            quarter_turns: 0,
+            flipped: true,
+        },
+        Orientation {
+            quarter_turns: 1,
+            flipped: true,
+        },
+        Orientation {
+            quarter_turns: 2,
+            flipped: true,
+        },
+        Orientation {
+            quarter_turns: 3,
+            flipped: true,
+        },
+    ];
+}
+
+impl<T> Grid<T>
+where
+    T: Clone + Copy,
+{
+    /// A `width` x `height` grid where each cell is copied from the position of this one that
+    /// `source(row, col)` gives.
+    fn remap<F>(&self, width: usize, height: usize, source: F) -> Grid<T>
+    where
+        F: Fn(isize, isize) -> (isize, isize),
+    {
+        let mut cells = Vec::with_capacity(width * height);
+        for row in 0..height as isize {
+            for col in 0..width as isize {
+                let (source_row, source_col) = source(row, col);
+                cells.push(self.get_cell(source_row, source_col));
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Rows become columns, mirroring the grid along its top-left to bottom-right diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |row, col| (col, row))
+    }
+
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let last_row = self.height as isize - 1;
+        self.remap(self.height, self.width, |row, col| (last_row - col, row))
+    }
+
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let last_col = self.width as isize - 1;
+        self.remap(self.height, self.width, |row, col| (col, last_col - row))
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (last_row, last_col) = (self.height as isize - 1, self.width as isize - 1);
+        self.remap(self.width, self.height, |row, col| {
+            (last_row - row, last_col - col)
+        })
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let last_col = self.width as isize - 1;
+        self.remap(self.width, self.height, |row, col| (row, last_col - col))
+    }
+
+    /// Mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let last_row = self.height as isize - 1;
+        self.remap(self.width, self.height, |row, col| (last_row - row, col))
+    }
+
+    pub fn oriented(&self, orientation: Orientation) -> Grid<T> {
+        let mut grid = if orientation.flipped {
+            self.flip_horizontal()
+        } else {
+            self.clone()
+        };
+        for _ in 0..orientation.quarter_turns % 4 {
+            grid = grid.rotate_cw();
+        }
+        grid
+    }
+
+    /// The grid in every one of `orientations` that looks different, skipping any that come out
+    /// the same as an earlier one (a symmetric pattern has fewer than 8), so a match against them
+    /// is only counted once.
+    pub fn distinct_orientations(&self, orientations: &[Orientation]) -> Vec<(Orientation, Grid<T>)>
+    where
+        T: PartialEq,
+    {
+        let mut distinct: Vec<(Orientation, Grid<T>)> = vec![];
+        for orientation in orientations {
+            let grid = self.oriented(*orientation);
+            if distinct.iter().all(|(_, seen)| *seen != grid) {
+                distinct.push((*orientation, grid));
+            }
+        }
+        distinct
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Orientation;
+    use crate::Grid;
+
+    #[test]
+    fn transforms_and_orientations() {
+        let parse_chars = |text| Grid::parse(text, |c, _pos| Ok::<_, String>(c)).unwrap();
+        let grid = parse_chars("abc\ndef");
+        assert_eq!(grid.transpose(), parse_chars("ad\nbe\ncf"));
+        assert_eq!(grid.rotate_cw(), parse_chars("da\neb\nfc"));
+        assert_eq!(grid.rotate_ccw(), parse_chars("cf\nbe\nad"));
+        assert_eq!(grid.rotate_180(), parse_chars("fed\ncba"));
+        assert_eq!(grid.flip_horizontal(), parse_chars("cba\nfed"));
+        assert_eq!(grid.flip_vertical(), parse_chars("def\nabc"));
+        for orientation in Orientation::ALL {
+            let round_trip = (0..4).fold(grid.oriented(orientation), |g, _| g.rotate_cw());
+            assert_eq!(round_trip, grid.oriented(orientation));
+        }
+        assert_eq!(grid.distinct_orientations(&Orientation::ALL).len(), 8);
+        let x = parse_chars("M.S\n.A.\nM.S");
+        let x_orientations = x.distinct_orientations(&Orientation::ALL);
+        assert_eq!(x_orientations.len(), 4);
+        assert!(x_orientations
+            .iter()
+            .all(|(orientation, _)| !orientation.flipped));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Grid, GridPos};
+
+/// Which axes positions wrap around on: both for a torus, or just one for a cylinder.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Wrap {
+    /// Columns wrap; going off the right edge comes back on the left.
+    Horizontal,
+    /// Rows wrap; going off the bottom edge comes back at the top.
+    Vertical,
+    Both,
+}
+
+impl Wrap {
+    fn wraps_cols(self) -> bool {
+        self != Wrap::Vertical
+    }
+
+    fn wraps_rows(self) -> bool {
+        self != Wrap::Horizontal
+    }
+}
+
+/// The size of a grid whose edges wrap around, for moving positions around it without needing
+/// any cells (e.g. robots on a torus). Make one with [`WrappingBounds::new`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct WrappingBounds {
+    width: usize,
+    height: usize,
+    wrap: Wrap,
+}
+
+/// Where `pos` ends up along one axis after `steps` steps of `vel`. Along a wrapping axis the
+/// velocity and step count are brought down modulo `size` first, so it never overflows however
+/// many steps are taken; along any other axis it stops at the ends of `isize`.
+fn advance_axis(pos: isize, vel: isize, steps: usize, size: usize, wraps: bool) -> isize {
+    if !wraps {
+        let moved = (vel as i128).saturating_mul(steps as i128);
+        return (pos as i128 + moved).clamp(isize::MIN as i128, isize::MAX as i128) as isize;
+    }
+
+    let size = size as i128;
+    let moved = (vel as i128).rem_euclid(size) * (steps as i128 % size);
+    (pos as i128 + moved).rem_euclid(size) as isize
+}
+
+impl WrappingBounds {
+    /// Bounds `width` x `height` that wrap along `wrap`, or `None` if either is 0, since there'd
+    /// be nowhere to wrap around to.
+    pub fn new(width: usize, height: usize, wrap: Wrap) -> Option<WrappingBounds> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        Some(WrappingBounds {
+            width,
+            height,
+            wrap,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn wrap(&self) -> Wrap {
+        self.wrap
+    }
+
+    /// `pos` brought back within the bounds along each wrapping axis. Along an axis that doesn't
+    /// wrap it's left alone, so it may still be out of bounds.
+    pub fn wrap_pos(&self, pos: GridPos) -> GridPos {
+        GridPos {
+            row: if self.wrap.wraps_rows() {
+                pos.row.rem_euclid(self.height as isize)
+            } else {
+                pos.row
+            },
+            col: if self.wrap.wraps_cols() {
+                pos.col.rem_euclid(self.width as isize)
+            } else {
+                pos.col
+            },
+        }
+    }
+
+    pub fn is_pos_in_bounds(&self, pos: GridPos) -> bool {
+        let pos = self.wrap_pos(pos);
+        pos.row >= 0
+            && pos.col >= 0
+            && (pos.row as usize) < self.height
+            && (pos.col as usize) < self.width
+    }
+
+    /// Where something at `pos` moving `vel` (rows, cols) per step ends up after `steps` steps, in
+    /// one jump rather than step by step.
+    pub fn advance(&self, pos: GridPos, vel: (isize, isize), steps: usize) -> GridPos {
+        GridPos {
+            row: advance_axis(pos.row, vel.0, steps, self.height, self.wrap.wraps_rows()),
+            col: advance_axis(pos.col, vel.1, steps, self.width, self.wrap.wraps_cols()),
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone + Copy,
+{
+    /// The grid's size as [`WrappingBounds`], or `None` if the grid is empty.
+    pub fn wrapping(&self, wrap: Wrap) -> Option<WrappingBounds> {
+        WrappingBounds::new(self.width, self.height, wrap)
+    }
+
+    /// The cell at `pos` after wrapping it around the grid, or `None` if it's out of bounds along
+    /// an axis that doesn't wrap (or the grid is empty).
+    pub fn get_wrapped(&self, pos: GridPos, wrap: Wrap) -> Option<T> {
+        self.get(self.wrapping(wrap)?.wrap_pos(pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Wrap, WrappingBounds};
+    use crate::{Grid, GridPos};
+
+    #[test]
+    fn wraps_on_chosen_axes() {
+        let grid = Grid::parse("ab\ncd\nef", |c, _pos| Ok::<_, String>(c)).unwrap();
+        let far = GridPos { row: -1, col: 5 };
+        assert_eq!(grid.get_wrapped(far, Wrap::Both), Some('f'));
+        assert_eq!(grid.get_wrapped(far, Wrap::Horizontal), None);
+        assert_eq!(
+            grid.get_wrapped(GridPos { row: 1, col: -3 }, Wrap::Horizontal),
+            Some('d')
+        );
+        assert_eq!(
+            grid.get_wrapped(GridPos { row: 4, col: 1 }, Wrap::Vertical),
+            Some('d')
+        );
+
+        // one jump lands where the same number of single steps would
+        let bounds = grid.wrapping(Wrap::Both).unwrap();
+        let start = GridPos { row: 2, col: 0 };
+        let mut stepped = start;
+        for _ in 0..7 {
+            stepped = bounds.advance(stepped, (2, -3), 1);
+        }
+        assert_eq!(bounds.advance(start, (2, -3), 7), stepped);
+        assert_eq!(stepped, GridPos { row: 1, col: 1 });
+        assert!(bounds.is_pos_in_bounds(GridPos { row: -7, col: 9 }));
+        assert!(!grid
+            .wrapping(Wrap::Vertical)
+            .unwrap()
+            .is_pos_in_bounds(GridPos { row: -7, col: 9 }));
+
+        // steps and velocities far too big to multiply out still land in the right place: a
+        // whole number of laps comes back to the start, and two half jumps make the whole one
+        let torus = WrappingBounds::new(101, 103, Wrap::Both).unwrap();
+        let start = GridPos { row: 5, col: 7 };
+        assert_eq!(
+            torus.advance(start, (-3, 4), 101 * 103 * 1_000_000_000_000),
+            start
+        );
+        let (vel, steps) = ((isize::MAX, isize::MIN), usize::MAX);
+        let halfway = torus.advance(start, vel, steps / 2);
+        assert_eq!(
+            torus.advance(halfway, vel, steps - steps / 2),
+            torus.advance(start, vel, steps)
+        );
+        let cylinder = WrappingBounds::new(5, 5, Wrap::Horizontal).unwrap();
+        assert_eq!(
+            cylinder
+                .advance(GridPos { row: 0, col: 0 }, (1, 1), steps)
+                .row,
+            isize::MAX
+        );
+
+        // there's nowhere to wrap to on an empty grid
+        assert_eq!(WrappingBounds::new(0, 3, Wrap::Vertical), None);
+        assert_eq!(Grid::new(3, 0, 'a').get_wrapped(start, Wrap::Both), None);
+    }
+}
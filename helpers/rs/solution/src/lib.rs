@@ -0,0 +1,112 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// Why a part didn't give an answer. Any `String` error converts into a failure, so `?` works on
+/// the days' own errors.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The part was asked for its `--help`, which is this. Not a failure.
+    Help(String),
+    Failed(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Help(help) => write!(f, "{}", help),
+            Error::Failed(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl From<String> for Error {
+    fn from(reason: String) -> Error {
+        Error::Failed(reason)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(reason: &str) -> Error {
+        Error::Failed(reason.to_string())
+    }
+}
+
+impl From<input_helpers::OptionsError> for Error {
+    fn from(error: input_helpers::OptionsError) -> Error {
+        match error {
+            input_helpers::OptionsError::Help(help) => Error::Help(help),
+            input_helpers::OptionsError::Invalid(reason) => Error::Failed(reason),
+        }
+    }
+}
+
+/// The input file a day's own binary takes as its first arg. The options can only be listed by a
+/// part once there's an input to hand it, so `--help` in place of the input file just says that.
+pub fn input_file_arg(args: &[String]) -> Result<&str, Error> {
+    match args.first().map(String::as_str) {
+        Some("-h") | Some("--help") => Err(Error::Help(String::from(
+            "pass --help after the input file to list the options",
+        ))),
+        _ => Ok(input_helpers::get_nth_string_arg(args, 0)?),
+    }
+}
+
+/// A day's puzzle. The input file is parsed once and then handed to each part, so each part
+/// returns its answer instead of printing it.
+///
+/// `args` are whatever extra args the caller was given (e.g. `-v` or `preamble=5`).
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input_file: &str) -> Result<Self::Input, String>;
+    fn part1(input: &Self::Input, args: &[String]) -> Result<Self::Answer1, Error>;
+    fn part2(input: &Self::Input, args: &[String]) -> Result<Self::Answer2, Error>;
+}
+
+/// Parses `input_file` and solves a single part (1 or 2) of `S`, returning the displayed answer.
+pub fn solve_part<S: Solution>(input_file: &str, part: u8, args: &[String]) -> Result<String, Error> {
+    let input = S::parse(input_file)?;
+    match part {
+        1 => S::part1(&input, args).map(|answer| answer.to_string()),
+        2 => S::part2(&input, args).map(|answer| answer.to_string()),
+        _ => Err(format!("Invalid part {}! Expected 1 or 2", part).into()),
+    }
+}
+
+/// How long each stage of a single run took. A part is `None` if it wasn't asked for.
+pub struct RunTimes {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+/// Parses `input_file` and solves the given `parts` of `S`, timing the parse and each part
+/// separately.
+pub fn time_run<S: Solution>(input_file: &str, parts: &[u8], args: &[String]) -> Result<RunTimes, Error> {
+    let parse_start = Instant::now();
+    let input = S::parse(input_file)?;
+    let mut run_times = RunTimes {
+        parse: parse_start.elapsed(),
+        part1: None,
+        part2: None,
+    };
+
+    for part in parts {
+        let part_start = Instant::now();
+        match part {
+            1 => {
+                S::part1(&input, args)?;
+                run_times.part1 = Some(part_start.elapsed());
+            }
+            2 => {
+                S::part2(&input, args)?;
+                run_times.part2 = Some(part_start.elapsed());
+            }
+            _ => return Err(format!("Invalid part {}! Expected 1 or 2", part).into()),
+        }
+    }
+
+    Ok(run_times)
+}